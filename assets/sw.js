@@ -1,4 +1,6 @@
-var cacheName = 'egui-template-pwa';
+// Replaced by a Trunk hook on every build (see `Trunk.toml`), so browsers install a new worker.
+var buildVersion = '__BUILD_VERSION__';
+var cacheName = 'egui-template-pwa-' + buildVersion;
 var filesToCache = [
   './',
   './index.html',
@@ -15,6 +17,26 @@ self.addEventListener('install', function (e) {
   );
 });
 
+/* Drop the caches of previous builds once this one takes over */
+self.addEventListener('activate', function (e) {
+  e.waitUntil(
+    caches.keys().then(function (keys) {
+      return Promise.all(
+        keys
+          .filter(function (key) { return key !== cacheName; })
+          .map(function (key) { return caches.delete(key); })
+      );
+    })
+  );
+});
+
+/* The page asks a waiting worker to take over when the user chooses to reload */
+self.addEventListener('message', function (e) {
+  if (e.data === 'skipWaiting') {
+    self.skipWaiting();
+  }
+});
+
 /* Serve cached content when offline */
 self.addEventListener('fetch', function (e) {
   e.respondWith(
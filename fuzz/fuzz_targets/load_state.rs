@@ -0,0 +1,14 @@
+//! Feed arbitrary bytes to the saved state loader, it must never panic.
+//!
+//! Run with `cargo +nightly fuzz run load_state`, seed the corpus from `tests/fixtures/state`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // eframe storage only ever holds strings.
+    if let Ok(saved) = std::str::from_utf8(data) {
+        let _ = eframe_template::check_saved_state(saved);
+    }
+});
@@ -1,17 +1,61 @@
 use crate::context::Context;
 // use crate::tab_viewer::AppTabViewer;
 use crate::prelude::*;
+use crate::project::{Project, ProjectPicker, RecentProjects};
+use crate::route::Route;
+use crate::sidepanel::{Navigator, SectionEnv, SidePanelSection, TreeInspector};
+use crate::storage::StorageNotice;
 use crate::tabs::tab_a::TabA;
 use crate::tabs::tab_b::TabB;
-use crate::tabs::{Tab, TabKind, TreeBehavior};
+use crate::tabs::{Tab, TabKind, TabKindDiscriminants, TreeBehavior};
+use crate::undo::UndoTarget;
 use crate::windows::{UniqueWindows, WindowKind, WindowToggleButtonsLocations};
-use egui::{CentralPanel, Id, ScrollArea, SidePanel, TopBottomPanel, Ui};
+use egui::{
+    CentralPanel, Id, Key, KeyboardShortcut, Modifiers, ScrollArea, SidePanel, TopBottomPanel, Ui,
+    WidgetInfo, WidgetType,
+};
+use egui_tiles::{
+    Behavior as _, Container, ContainerInsertion, ContainerKind, InsertionPoint, Tile, TileId,
+};
 use serde::{Deserialize, Serialize};
-use strum::IntoEnumIterator;
+
+const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+/// Move the keyboard focus between the visible panes, like F6 does in browsers.
+const NEXT_PANE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::NONE, Key::F6);
+const PREVIOUS_PANE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::SHIFT, Key::F6);
+const MAXIMIZE_PANE_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::M);
+const DISTRACTION_FREE_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::F);
+/// Leaves the maximized pane and distraction-free mode.
+const RESTORE_PANE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::NONE, Key::Escape);
 
 pub struct TemplateApp {
     cx: Context,
     state: State,
+    /// Storage key of the state, depends on the workspace.
+    storage_key: String,
+    #[cfg(target_arch = "wasm32")]
+    history: Option<crate::route::WebHistory>,
+    #[cfg(target_arch = "wasm32")]
+    update_banner: Option<crate::pwa::UpdateBanner>,
+    /// Shown when the state could not be loaded or saved.
+    storage_notice: Option<StorageNotice>,
+    #[cfg(not(target_arch = "wasm32"))]
+    crash_dialog: Option<crate::crash::CrashDialog>,
+    #[cfg(not(target_arch = "wasm32"))]
+    event_collector: egui_tracing::EventCollector,
+    /// Projects of File → Open Recent, shared by all workspaces.
+    recent_projects: RecentProjects,
+    project_picker: ProjectPicker,
+    /// Pane shown alone in the central panel, the tile tree stays as it is meanwhile.
+    maximized: Option<TileId>,
+    /// Also hide the top bar and the side panel while a pane is maximized.
+    distraction_free: bool,
+    /// The tile tree as of the last recorded change, the "before" of the next layout undo entry.
+    layout_snapshot: Option<String>,
     confirm_close_modal_open: bool,
     // shutdown_event_tx: Option<oneshot::Sender<()>>,
     shutdown_confirmed: bool,
@@ -24,6 +68,13 @@ struct State {
     tabs_behavior: TreeBehavior,
     side_panel_expanded: bool,
     windows: UniqueWindows,
+    #[serde(skip)]
+    inspector: TreeInspector,
+    #[serde(default)]
+    navigator: Navigator,
+    /// Its layout is saved next to it, in addition to the state.
+    #[serde(default)]
+    project: Option<Project>,
 }
 
 impl Default for State {
@@ -33,6 +84,8 @@ impl Default for State {
             let view = Tab {
                 kind,
                 nr: next_view_nr,
+                custom_title: None,
+                failure: None,
             };
             next_view_nr += 1;
             view
@@ -54,6 +107,38 @@ impl Default for State {
             tabs_behavior: Default::default(),
             side_panel_expanded: true,
             windows: Default::default(),
+            inspector: Default::default(),
+            navigator: Default::default(),
+            project: None,
+        }
+    }
+}
+
+/// How the app should start, e.g. from command-line arguments.
+#[derive(Default, Debug)]
+pub struct StartupOptions {
+    /// RON file with a tile layout to use instead of the persisted one.
+    pub layout: Option<std::path::PathBuf>,
+    /// Name of a separately persisted state.
+    pub workspace: Option<String>,
+    /// Ignore the persisted state.
+    pub reset_state: bool,
+    /// Tab to focus after loading, e.g. from a deep link.
+    pub route: Option<Route>,
+    /// Project to open, with its layout.
+    pub project: Option<Project>,
+}
+
+/// Storage key of the recent projects, the same for all workspaces.
+fn recent_projects_key() -> String {
+    format!("{}/recent_projects", eframe::APP_KEY)
+}
+
+impl StartupOptions {
+    fn storage_key(&self) -> String {
+        match &self.workspace {
+            Some(workspace) => format!("{}/workspace/{workspace}", eframe::APP_KEY),
+            None => eframe::APP_KEY.to_owned(),
         }
     }
 }
@@ -63,164 +148,472 @@ impl TemplateApp {
     pub fn new(
         cc: &eframe::CreationContext<'_>,
         cx: Context,
+        options: StartupOptions,
         #[cfg(not(target_arch = "wasm32"))] event_collector: egui_tracing::EventCollector,
         // shutdown_event_tx: oneshot::Sender<()>,
     ) -> Self {
+        // On the web the page URL decides the workspace and the focused tab.
+        #[cfg(target_arch = "wasm32")]
+        let options = {
+            let route = Route::from_location(&cc.integration_info.web_info.location);
+            StartupOptions {
+                workspace: options.workspace.or_else(|| route.workspace.clone()),
+                route: Some(route),
+                ..options
+            }
+        };
+        #[cfg(target_arch = "wasm32")]
+        let route = options.route.clone().unwrap_or_default();
+
+        cx.notifications().set_egui_ctx(cc.egui_ctx.clone());
+        cx.tasks().set_egui_ctx(cc.egui_ctx.clone());
+        let mut app = Self::with_storage(
+            cc.storage,
+            cx,
+            options,
+            #[cfg(not(target_arch = "wasm32"))]
+            event_collector,
+        );
+        app.state.apply_settings(&cc.egui_ctx);
+        #[cfg(not(target_arch = "wasm32"))]
+        let app = Self {
+            crash_dialog: crate::crash::previous_report().map(crate::crash::CrashDialog::new),
+            ..app
+        };
+        #[cfg(target_arch = "wasm32")]
+        let app = Self {
+            history: crate::route::WebHistory::new(&cc.egui_ctx, route),
+            update_banner: Some(crate::pwa::UpdateBanner::new(&cc.egui_ctx)),
+            ..app
+        };
+        app
+    }
+
+    /// Same as [`TemplateApp::new`], but restores state from the given storage instead of the one
+    /// provided by eframe. Useful for tests.
+    pub fn with_storage(
+        storage: Option<&dyn eframe::Storage>,
+        cx: Context,
+        options: StartupOptions,
+        #[cfg(not(target_arch = "wasm32"))] event_collector: egui_tracing::EventCollector,
+    ) -> Self {
+        let storage_key = options.storage_key();
+
         // Load previous app state (if any).
-        let mut state = if let Some(storage) = cc.storage {
-            eframe::get_value(storage, eframe::APP_KEY).unwrap_or_else(|| {
-                info!("Default state created, because deserializing failed");
+        let mut storage_notice = None;
+        let mut state = match storage {
+            _ if options.reset_state => {
+                info!("Default state created, because of reset request");
                 State::default()
-            })
-        } else {
-            info!("Default state created, because persistence is disabled");
-            State::default()
+            }
+            Some(storage) => match storage.get_string(&storage_key) {
+                Some(saved) => State::decode(&saved).unwrap_or_else(|e| {
+                    warn!("Default state created, because {e}");
+                    storage_notice = Some(StorageNotice::load_failed(e, saved));
+                    State::default()
+                }),
+                None => {
+                    info!("Default state created, because {}", LoadError::Missing);
+                    State::default()
+                }
+            },
+            None => {
+                info!("Default state created, because persistence is disabled");
+                State::default()
+            }
         };
 
-        // When adding a new window during development, restored state won't have new windows, take care of it:
-        if state.windows.windows.len() != WindowKind::iter().size_hint().0 {
-            state.windows = Default::default();
-        }
+        let recent_projects = storage
+            .and_then(|storage| storage.get_string(&recent_projects_key()))
+            .and_then(|saved| match ron::from_str(&saved) {
+                Ok(recent_projects) => Some(recent_projects),
+                Err(e) => {
+                    warn!("Failed to load the recent projects: {e}");
+                    None
+                }
+            })
+            .unwrap_or_default();
 
-        // Restore contexts for windows
-        for (_window, _) in &mut state.windows.windows {
-            #[cfg(not(target_arch = "wasm32"))]
-            if let WindowKind::LogViewer(log_viewer) = _window {
-                log_viewer.set_collector(event_collector);
-                break;
+        if let Some(path) = &options.layout {
+            match State::load_layout(path) {
+                Ok(tabs) => state.tabs = tabs,
+                Err(e) => error!("Failed to load layout from {}: {e}", path.display()),
             }
         }
 
-        // Restore context for tabs
-        state.tabs_behavior.feed_cx(cx.clone());
+        if let Some(route) = &options.route {
+            route.apply(&mut state.tabs, &mut state.tabs_behavior);
+        }
+
+        state.attach(
+            &cx,
+            #[cfg(not(target_arch = "wasm32"))]
+            &event_collector,
+        );
 
-        TemplateApp {
+        let mut app = TemplateApp {
             cx,
             state,
+            storage_key,
+            #[cfg(target_arch = "wasm32")]
+            history: None,
+            #[cfg(target_arch = "wasm32")]
+            update_banner: None,
+            storage_notice,
+            #[cfg(not(target_arch = "wasm32"))]
+            crash_dialog: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            event_collector,
+            recent_projects,
+            project_picker: Default::default(),
+            maximized: None,
+            distraction_free: false,
+            layout_snapshot: None,
             // log_viewer,
             confirm_close_modal_open: false,
             // shutdown_event_tx: Some(shutdown_event_tx),
             shutdown_confirmed: false,
+        };
+        if let Some(project) = options.project {
+            app.open_project(project);
         }
+        app
     }
 
-    fn menu_bar(&mut self, ui: &mut Ui) {
-        ui.toggle_value(&mut self.state.side_panel_expanded, "*")
-            .on_hover_text("Show/Hide side panel");
-        ui.separator();
-        let is_web = cfg!(target_arch = "wasm32");
-        ui.menu_button("File", |ui| {
-            let is_clicked = self
-                .state
-                .windows
-                .toggle_buttons(WindowToggleButtonsLocations::File, ui);
-            if is_clicked {
-                ui.close_menu();
-            }
-            // NOTE: no File->Quit on web pages!
-            if !is_web {
-                if ui.button("Quit").clicked() {
-                    ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
-                }
-            }
-        });
-        ui.menu_button("Window", |ui| {
-            let is_clicked = self
-                .state
-                .windows
-                .toggle_buttons(WindowToggleButtonsLocations::Window, ui);
-            if is_clicked {
-                ui.close_menu();
-            }
-        });
-        ui.menu_button("Help", |ui| {
-            let mut is_clicked = self
-                .state
-                .windows
-                .toggle_buttons(WindowToggleButtonsLocations::Help, ui);
-            if ui.button("Reset mem").clicked() {
-                ui.ctx().memory_mut(|memory| {
-                    *memory = Default::default();
-                });
-                self.state = State::default();
-                self.state.tabs_behavior.feed_cx(self.cx.clone());
-                is_clicked = true;
-            }
-            if is_clicked {
-                ui.close_menu();
-            }
-        });
-        ui.add_space(16.0);
+    /// Titles of all panes in the tile tree, sorted alphabetically.
+    pub fn pane_titles(&self) -> Vec<String> {
+        let mut titles: Vec<String> = self
+            .state
+            .tabs
+            .tiles
+            .iter()
+            .filter_map(|(_, tile)| match tile {
+                Tile::Pane(tab) => Some(tab.title(&self.cx).text().to_owned()),
+                Tile::Container(_) => None,
+            })
+            .collect();
+        titles.sort();
+        titles
+    }
 
-        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            egui::warn_if_debug_build(ui);
-            egui::widgets::global_theme_preference_buttons(ui);
-        });
+    /// Kind of the container the pane with the given title is in, `None` if it is the root.
+    pub fn pane_container(&self, title: &str) -> Option<ContainerKind> {
+        let tiles = &self.state.tabs.tiles;
+        let pane = tiles.iter().find_map(|(tile_id, tile)| match tile {
+            Tile::Pane(tab) if tab.title(&self.cx).text() == title => Some(*tile_id),
+            _ => None,
+        })?;
+        match tiles.get(tiles.parent_of(pane)?)? {
+            Tile::Container(container) => Some(container.kind()),
+            Tile::Pane(_) => None,
+        }
     }
 
-    fn side_panel(&mut self, ui: &mut Ui) {
-        self.state.tabs_behavior.ui(ui);
+    /// Title and number of the pane the user last clicked into or moved the focus to.
+    pub fn focused_pane(&self) -> Option<(String, usize)> {
+        let tile_id = self.state.tabs_behavior.focused?;
+        match self.state.tabs.tiles.get(tile_id)? {
+            Tile::Pane(tab) => Some((tab.title(&self.cx).text().to_owned(), tab.nr)),
+            Tile::Container(_) => None,
+        }
+    }
 
-        ui.collapsing("Tree", |ui| {
-            ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
-            let tree_debug = format!("{:#?}", self.state.tabs);
-            ui.monospace(&tree_debug);
-        });
+    /// Whether the window with the given title (as shown in the menus) is open.
+    pub fn is_window_open(&self, title: &str) -> bool {
+        self.state.windows.is_open(title)
+    }
 
-        ui.separator();
+    pub fn is_side_panel_expanded(&self) -> bool {
+        self.state.side_panel_expanded
+    }
 
-        ui.collapsing("Active tiles", |ui| {
-            let active = self.state.tabs.active_tiles();
-            for tile_id in active {
-                use egui_tiles::Behavior as _;
-                let name = self
-                    .state
-                    .tabs_behavior
-                    .tab_title_for_tile(&self.state.tabs.tiles, tile_id);
-                ui.label(format!("{} - {tile_id:?}", name.text()));
+    /// Add a section to the side panel, replacing a registered one with the same id.
+    pub fn register_side_panel_section(&mut self, section: Box<dyn SidePanelSection>) {
+        self.state.navigator.register(section);
+    }
+
+    pub fn navigator(&self) -> &Navigator {
+        &self.state.navigator
+    }
+
+    /// Title of the pane shown alone, see [`TemplateApp::toggle_maximized`].
+    pub fn maximized_pane(&self) -> Option<String> {
+        match self.state.tabs.tiles.get(self.maximized?)? {
+            Tile::Pane(tab) => Some(tab.title(&self.cx).text().to_owned()),
+            Tile::Container(_) => None,
+        }
+    }
+
+    pub fn is_distraction_free(&self) -> bool {
+        self.distraction_free
+    }
+
+    /// Show the focused pane alone, or go back to the tiles. In distraction-free mode the top bar
+    /// and the side panel are hidden too. Switching between the two modes keeps the pane.
+    pub fn toggle_maximized(&mut self, distraction_free: bool) {
+        if self.maximized.is_some() && self.distraction_free == distraction_free {
+            debug!("Restoring the tiles");
+            self.maximized = None;
+            self.distraction_free = false;
+            return;
+        }
+        let tree = &self.state.tabs;
+        let pane = self
+            .maximized
+            .or(self.state.tabs_behavior.focused)
+            .or_else(|| {
+                tree.active_tiles()
+                    .into_iter()
+                    .find(|tile_id| matches!(tree.tiles.get(*tile_id), Some(Tile::Pane(_))))
+            });
+        debug!("Maximizing {pane:?}, distraction-free: {distraction_free}");
+        self.maximized = pane;
+        self.distraction_free = distraction_free && pane.is_some();
+    }
+
+    pub fn project(&self) -> Option<&Project> {
+        self.state.project.as_ref()
+    }
+
+    /// Pinned projects first, then the most recent.
+    pub fn recent_projects(&self) -> &RecentProjects {
+        &self.recent_projects
+    }
+
+    /// Close the current project and switch to the layout of the given one, if it has one.
+    pub fn open_project(&mut self, project: Project) {
+        self.close_project();
+        info!("Opening project {}", project.path.display());
+        match project.load_layout().and_then(|layout| match layout {
+            Some(layout) => State::decode_layout(&layout).map(Some),
+            None => Ok(None),
+        }) {
+            Ok(Some(tabs)) => {
+                self.state.tabs = tabs;
+                self.state.tabs_behavior.focused = None;
+                self.cx.undo().clear();
+                self.layout_snapshot = None;
             }
-        });
+            Ok(None) => {}
+            Err(e) => {
+                error!(
+                    "Failed to load the layout of {}: {e}",
+                    project.path.display()
+                );
+                self.cx.notify(
+                    NotifyLevel::Warning,
+                    self.cx
+                        .tr_args("project-layout-failed", &[("name", &project.name())]),
+                );
+            }
+        }
+        self.recent_projects.add(project.clone());
+        self.state.project = Some(project);
+    }
 
-        ui.separator();
+    /// Save the layout of the current project and forget it, the tiles stay as they are.
+    pub fn close_project(&mut self) {
+        if let Some(project) = self.state.project.take() {
+            info!("Closing project {}", project.path.display());
+            self.save_project_layout(&project);
+        }
+    }
 
-        if let Some(root) = self.state.tabs.root() {
-            crate::sidepanel::tree_ui(
-                ui,
-                &mut self.state.tabs_behavior,
-                &mut self.state.tabs.tiles,
-                root,
+    fn save_project_layout(&self, project: &Project) {
+        let result = ron::to_string(&self.state.tabs)
+            .map_err(|e| e.to_string())
+            .and_then(|layout| project.save_layout(&layout));
+        if let Err(e) = result {
+            error!(
+                "Failed to save the layout of {}: {e}",
+                project.path.display()
             );
         }
     }
-}
 
-impl eframe::App for TemplateApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            egui::menu::bar(ui, |ui| {
-                self.menu_bar(ui);
+    fn save_state(&mut self, storage: &mut dyn eframe::Storage) {
+        // Same as `eframe::set_value`, but keeps track of the serialized size for the Debug window.
+        match ron::to_string(&self.state) {
+            Ok(serialized) => {
+                let result = crate::storage::set_string(storage, &self.storage_key, &serialized);
+                let mut diagnostics = self.cx.diagnostics();
+                diagnostics.last_save_size = Some(serialized.len());
+                match result {
+                    Ok(()) => diagnostics.last_save_error = None,
+                    Err(e) => {
+                        error!("Failed to save app state: {e}");
+                        // Only bother the user once until saving works again.
+                        let is_new = diagnostics.last_save_error.is_none();
+                        diagnostics.last_save_error = Some(e.to_string());
+                        if is_new && self.storage_notice.is_none() {
+                            self.storage_notice = Some(StorageNotice::save_failed(e, serialized));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!("Failed to serialize app state: {e}");
+            }
+        }
+    }
+
+    /// Draw a frame, see [`eframe::App::update`].
+    fn show(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        crate::profiler::new_frame();
+        profile_function!();
+        self.cx.diagnostics().on_frame(ctx, frame, &self.state.tabs);
+        self.cx.undo().begin_frame(ctx.input(|i| i.time));
+        self.cx.tasks().begin_frame(ctx.input(|i| i.time));
+
+        // A focused text field handles the shortcuts itself.
+        if !ctx.wants_keyboard_input() {
+            // Redo first, undo would match it too.
+            if ctx.input_mut(|i| i.consume_shortcut(&REDO_SHORTCUT)) {
+                self.step_history(true);
+            } else if ctx.input_mut(|i| i.consume_shortcut(&UNDO_SHORTCUT)) {
+                self.step_history(false);
+            }
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&MAXIMIZE_PANE_SHORTCUT)) {
+            self.toggle_maximized(false);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&DISTRACTION_FREE_SHORTCUT)) {
+            self.toggle_maximized(true);
+        } else if self.maximized.is_some()
+            && !ctx.wants_keyboard_input()
+            && ctx.input_mut(|i| i.consume_shortcut(&RESTORE_PANE_SHORTCUT))
+        {
+            self.toggle_maximized(self.distraction_free);
+        }
+        // Undo or closing it may have removed the maximized pane.
+        if let Some(tile_id) = self.maximized {
+            if !matches!(self.state.tabs.tiles.get(tile_id), Some(Tile::Pane(_))) {
+                self.maximized = None;
+                self.distraction_free = false;
+            }
+        }
+        // Previous first, next would match it too.
+        if ctx.input_mut(|i| i.consume_shortcut(&PREVIOUS_PANE_SHORTCUT)) {
+            let state = &mut self.state;
+            state.tabs_behavior.focus_next_pane(&state.tabs, true);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&NEXT_PANE_SHORTCUT)) {
+            let state = &mut self.state;
+            state.tabs_behavior.focus_next_pane(&state.tabs, false);
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(update_banner) = &mut self.update_banner {
+            update_banner.show(ctx, &self.cx);
+        }
+
+        if !self.distraction_free {
+            TopBottomPanel::top("top_panel").show(ctx, |ui| {
+                profile_scope!("top_panel");
+                egui::menu::bar(ui, |ui| {
+                    self.menu_bar(ui);
+                });
             });
+        }
+
+        TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+            profile_scope!("status_bar");
+            self.status_bar(ui);
         });
 
         SidePanel::left("side_panel").resizable(true).show_animated(
             ctx,
-            self.state.side_panel_expanded,
+            self.state.side_panel_expanded && !self.distraction_free,
             |ui| {
+                profile_scope!("side_panel");
                 ScrollArea::vertical().show(ui, |ui| {
                     self.side_panel(ui);
                 });
             },
         );
 
-        if let Some(parent) = self.state.tabs_behavior.add_child_to.take() {
-            debug!("Add child to {:?}", parent);
+        if let Some((parent, kind)) = self.state.tabs_behavior.add_child_to.take() {
+            debug!("Add {} to {:?}", kind.as_ref(), parent);
+            self.state.add_tab(parent, kind);
+            self.state.tabs_behavior.layout_edit = Some("action-add-tab");
+        }
+        if let Some(tile_id) = self.state.tabs_behavior.close_pane.take() {
+            debug!("Closing failed tab {tile_id:?}");
+            self.state.tabs.remove_recursively(tile_id);
+            self.state.tabs_behavior.layout_edit = Some("action-close-tab");
         }
 
         self.state.windows.show_open_windows(&mut self.cx, ctx);
+        if let Some(settings) = self.state.windows.settings_mut() {
+            // Also changed by egui's zoom shortcuts.
+            settings.fonts.zoom = ctx.zoom_factor();
+        }
 
-        CentralPanel::default().show(ctx, |ui| {
-            self.state.tabs.ui(&mut self.state.tabs_behavior, ui);
-        });
+        if let Some(project) = self.project_picker.show(ctx, &self.cx) {
+            self.open_project(project);
+        }
+
+        crate::notifications::show_toasts(ctx, &self.cx);
+
+        if let Some(notice) = &mut self.storage_notice {
+            if !notice.show(ctx, &self.cx) {
+                self.storage_notice = None;
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(dialog) = &mut self.crash_dialog {
+            match dialog.show(ctx, &self.cx) {
+                Some(crate::crash::CrashChoice::Restore(saved)) => {
+                    match State::decode(&saved) {
+                        Ok(state) => {
+                            info!("Restored state from crash report");
+                            self.set_state(ctx, state);
+                        }
+                        Err(e) => error!("Failed to restore state from crash report: {e}"),
+                    }
+                    self.crash_dialog = None;
+                }
+                Some(crate::crash::CrashChoice::Dismiss) => self.crash_dialog = None,
+                None => {}
+            }
+        }
+
+        self.state.tabs_behavior.drop_target = None;
+        let central_rect = CentralPanel::default()
+            .show(ctx, |ui| {
+                profile_scope!("central_panel");
+                match self.maximized {
+                    Some(tile_id) => self.maximized_ui(ui, tile_id),
+                    None => {
+                        self.state.tabs.ui(&mut self.state.tabs_behavior, ui);
+                        self.state
+                            .inspector
+                            .highlight_selected(ui, &self.state.tabs.tiles);
+                    }
+                }
+            })
+            .response
+            .rect;
+        self.file_drop(ctx, central_rect);
+        if let Some(tile_id) = self.state.tabs_behavior.maximize_request.take() {
+            debug!("Maximizing {tile_id:?}");
+            self.maximized = Some(tile_id);
+            self.distraction_free = false;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(history) = &mut self.history {
+            history.sync(&mut self.state.tabs, &mut self.state.tabs_behavior);
+        }
+
+        let behavior = &mut self.state.tabs_behavior;
+        if behavior.resizing && !ctx.input(|i| i.pointer.any_down()) {
+            behavior.resizing = false;
+            behavior.layout_edit.get_or_insert("action-resize");
+        }
+        // Mid-drag the snapshot has to stay the one from before the resize.
+        let refresh = std::mem::take(&mut behavior.tab_selected) && !behavior.resizing;
+        let layout_edit = behavior.layout_edit.take();
+        self.record_layout(layout_edit.or(self.state.inspector.take_edit()), refresh);
 
         if ctx.input(|i| i.viewport().close_requested()) {
             if !self.shutdown_confirmed {
@@ -231,7 +624,7 @@ impl eframe::App for TemplateApp {
         if self.confirm_close_modal_open {
             egui::Modal::new(Id::new("Confirm Close Modal")).show(ctx, |ui| {
                 ui.set_width(200.0);
-                ui.heading("Confirm exit");
+                ui.heading(self.cx.tr("confirm-exit-title"));
 
                 ui.add_space(32.0);
 
@@ -239,13 +632,13 @@ impl eframe::App for TemplateApp {
                     ui,
                     |_ui| {},
                     |ui| {
-                        if ui.button("Yes").clicked() {
+                        if ui.button(self.cx.tr("confirm-exit-yes")).clicked() {
                             self.confirm_close_modal_open = false;
                             self.shutdown_confirmed = true;
                             ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
                         }
 
-                        if ui.button("No").clicked() {
+                        if ui.button(self.cx.tr("confirm-exit-no")).clicked() {
                             self.confirm_close_modal_open = false;
                             ui.close_menu();
                         }
@@ -255,8 +648,518 @@ impl eframe::App for TemplateApp {
         }
     }
 
+    /// The focused tab and its items on the left, the services and app status on the right.
+    fn status_bar(&mut self, ui: &mut Ui) {
+        let mut clicked = Vec::new();
+        let mut open_log = false;
+        let mut open_tasks = false;
+        ui.horizontal(|ui| {
+            let focused = self.state.tabs_behavior.focused.and_then(|tile_id| {
+                match self.state.tabs.tiles.get(tile_id) {
+                    Some(Tile::Pane(tab)) => Some(tab),
+                    _ => None,
+                }
+            });
+            match focused {
+                Some(tab) => {
+                    ui.label(tab.title(&self.cx));
+                    for item in tab.status_items(&self.cx) {
+                        clicked.extend(item.ui(ui));
+                    }
+                }
+                None => {
+                    ui.weak(self.cx.tr("status-no-tab"));
+                }
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if cfg!(debug_assertions) {
+                    let ms = self.cx.diagnostics().mean_frame_time() * 1000.0;
+                    ui.weak(
+                        self.cx
+                            .tr_args("status-frame-time", &[("ms", &format!("{ms:.1}"))]),
+                    );
+                }
+                if !cfg!(target_arch = "wasm32") {
+                    open_log = self.log_counts_ui(ui);
+                }
+                let tasks = self.cx.tasks().running();
+                if tasks > 0
+                    && ui
+                        .link(
+                            self.cx
+                                .tr_args("status-tasks", &[("count", &tasks.to_string())]),
+                        )
+                        .clicked()
+                {
+                    open_tasks = true;
+                }
+                let status_bar = self.cx.status_bar();
+                // Right to left, the first item ends up leftmost.
+                for item in status_bar.items().rev() {
+                    clicked.extend(item.ui(ui));
+                }
+            });
+        });
+
+        if open_log {
+            self.cx.status_bar().reset_log_counts();
+            self.state.windows.open("Log viewer");
+        }
+        if open_tasks {
+            self.state.windows.open("Tasks");
+        }
+        // Outside the lock, callbacks may set items.
+        for on_click in clicked {
+            on_click(&self.cx);
+        }
+    }
+
+    /// Warnings and errors logged since the Log viewer was last opened from here, returns
+    /// whether to open it.
+    fn log_counts_ui(&self, ui: &mut Ui) -> bool {
+        let (warnings, errors) = self.cx.status_bar().log_counts();
+        let color = |count: usize, color| {
+            if count > 0 {
+                color
+            } else {
+                ui.visuals().weak_text_color()
+            }
+        };
+        let mut text = egui::text::LayoutJob::default();
+        for (icon, count, count_color) in [
+            ("⚠", warnings, ui.visuals().warn_fg_color),
+            ("🗙", errors, ui.visuals().error_fg_color),
+        ] {
+            text.append(
+                &format!("{icon} {count} "),
+                0.0,
+                egui::TextFormat::simple(
+                    egui::TextStyle::Body.resolve(ui.style()),
+                    color(count, count_color),
+                ),
+            );
+        }
+        let name = self.cx.tr_args(
+            "status-log",
+            &[
+                ("warnings", &warnings.to_string()),
+                ("errors", &errors.to_string()),
+            ],
+        );
+        let response = ui
+            .add(egui::Button::new(text).frame(false))
+            .on_hover_text(self.cx.tr("status-log-hint"));
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, &name));
+        response.clicked()
+    }
+
+    fn menu_bar(&mut self, ui: &mut Ui) {
+        let toggle_name = self.cx.tr("side-panel-toggle");
+        ui.toggle_value(&mut self.state.side_panel_expanded, "*")
+            .on_hover_text(&toggle_name)
+            .widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::SelectableLabel,
+                    true,
+                    self.state.side_panel_expanded,
+                    &toggle_name,
+                )
+            });
+        ui.separator();
+        let is_web = cfg!(target_arch = "wasm32");
+        ui.menu_button(self.cx.tr("menu-file"), |ui| {
+            self.project_menu(ui);
+            ui.separator();
+            let is_clicked =
+                self.state
+                    .windows
+                    .toggle_buttons(WindowToggleButtonsLocations::File, &self.cx, ui);
+            if is_clicked {
+                ui.close_menu();
+            }
+            // NOTE: no File->Quit on web pages!
+            if !is_web {
+                if ui.button(self.cx.tr("menu-quit")).clicked() {
+                    ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            }
+        });
+        ui.menu_button(self.cx.tr("menu-edit"), |ui| {
+            self.edit_menu(ui);
+        });
+        ui.menu_button(self.cx.tr("menu-window"), |ui| {
+            let is_clicked = self.state.windows.toggle_buttons(
+                WindowToggleButtonsLocations::Window,
+                &self.cx,
+                ui,
+            );
+            if is_clicked {
+                ui.close_menu();
+            }
+            ui.separator();
+            self.maximize_menu(ui);
+        });
+        ui.menu_button(self.cx.tr("menu-help"), |ui| {
+            let mut is_clicked =
+                self.state
+                    .windows
+                    .toggle_buttons(WindowToggleButtonsLocations::Help, &self.cx, ui);
+            if ui.button(self.cx.tr("menu-reset-mem")).clicked() {
+                ui.ctx().memory_mut(|memory| {
+                    *memory = Default::default();
+                });
+                self.set_state(ui.ctx(), State::default());
+                is_clicked = true;
+            }
+            if is_clicked {
+                ui.close_menu();
+            }
+        });
+        ui.add_space(16.0);
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            egui::warn_if_debug_build(ui);
+            if let Some(settings) = self.state.windows.settings_mut() {
+                settings.theme.menu_ui(ui, &self.cx);
+            }
+        });
+    }
+
+    /// The maximized pane with a header to go back to the tiles, see
+    /// [`TemplateApp::toggle_maximized`].
+    fn maximized_ui(&mut self, ui: &mut Ui, tile_id: TileId) {
+        let behavior = &mut self.state.tabs_behavior;
+        let Some(Tile::Pane(tab)) = self.state.tabs.tiles.get_mut(tile_id) else {
+            return;
+        };
+        let mut restore = false;
+        ui.horizontal(|ui| {
+            let title = behavior.tab_title_for_pane(tab).strong();
+            let title = ui
+                .add(egui::Label::new(title).sense(egui::Sense::click()))
+                .on_hover_text(self.cx.tr("pane-restore-hint"));
+            restore |= title.double_clicked();
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let name = self.cx.tr("pane-restore");
+                let button = ui.small_button("🗗").on_hover_text(format!(
+                    "{name} ({})",
+                    ui.ctx().format_shortcut(&RESTORE_PANE_SHORTCUT)
+                ));
+                button.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, &name));
+                restore |= button.clicked();
+            });
+        });
+        ui.separator();
+        let rect = ui.available_rect_before_wrap();
+        ui.scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
+            behavior.pane_ui(ui, tile_id, tab);
+        });
+        behavior.focused = Some(tile_id);
+        if restore {
+            self.toggle_maximized(self.distraction_free);
+        }
+    }
+
+    /// Highlight where hovered files will open, and open the dropped ones, see [`crate::file_drop`].
+    fn file_drop(&mut self, ctx: &egui::Context, central_rect: egui::Rect) {
+        let (hovered, dropped) =
+            ctx.input(|i| (i.raw.hovered_files.clone(), i.raw.dropped_files.clone()));
+        if hovered.is_empty() && dropped.is_empty() {
+            return;
+        }
+        let pointer = ctx.input(|i| i.pointer.hover_pos());
+        let target = self
+            .state
+            .drop_point(self.state.tabs_behavior.drop_target, pointer);
+        if !hovered.is_empty() {
+            let rect = match target {
+                Some((_, rect)) => rect,
+                None => self
+                    .state
+                    .tabs
+                    .root()
+                    .and_then(|root| self.state.tabs.tiles.rect(root))
+                    .unwrap_or(central_rect),
+            };
+            // Natively the MIME type is unknown while hovering, and on the web the name.
+            let unsupported = hovered.iter().find_map(|file| {
+                let name = crate::file_drop::dropped_name(file.path.as_ref(), "");
+                let known = !name.is_empty() || !file.mime.is_empty();
+                let supported = TabKindDiscriminants::for_file(&name, &file.mime).is_some();
+                (known && !supported).then(|| {
+                    if name.is_empty() {
+                        file.mime.clone()
+                    } else {
+                        name
+                    }
+                })
+            });
+            crate::file_drop::hover_ui(ctx, &self.cx, rect, unsupported.as_deref());
+        }
+        for file in &dropped {
+            self.open_dropped_file(target.as_ref().map(|(point, _)| point), file);
+        }
+    }
+
+    /// Open the file in a new tab of the kind that declared it, at the given point or else in the
+    /// root container.
+    fn open_dropped_file(&mut self, target: Option<&InsertionPoint>, dropped: &egui::DroppedFile) {
+        let name = crate::file_drop::dropped_name(dropped.path.as_ref(), &dropped.name);
+        let Some(kind) = TabKindDiscriminants::for_file(&name, &dropped.mime) else {
+            warn!("No tab opens {name} ({})", dropped.mime);
+            self.cx.notify(
+                NotifyLevel::Warning,
+                self.cx.tr_args("file-drop-unsupported", &[("name", &name)]),
+            );
+            return;
+        };
+        let result = crate::file_drop::OpenedFile::read(dropped).and_then(|file| {
+            let mut tab = kind.create_tab(self.state.next_tab_nr());
+            tab.open_file(&file).map(|()| tab)
+        });
+        match result {
+            Ok(tab) => {
+                info!("Opened {name} in {}", kind.as_ref());
+                let tile_id = match target {
+                    Some(point) => self.state.insert_tab_at(point, tab),
+                    None => self.state.insert_tab(self.state.tabs.root(), tab),
+                };
+                self.state.tabs_behavior.focused = Some(tile_id);
+                self.state.tabs_behavior.layout_edit = Some("action-open-file");
+            }
+            Err(e) => {
+                error!("Failed to open {name}: {e}");
+                self.cx.notify(
+                    NotifyLevel::Error,
+                    self.cx
+                        .tr_args("file-drop-failed", &[("name", &name), ("error", &e)]),
+                );
+            }
+        }
+    }
+
+    /// Open, Open Recent and Close project of the File menu.
+    fn project_menu(&mut self, ui: &mut Ui) {
+        self.project_picker.menu_ui(ui, &self.cx);
+        let mut open = None;
+        ui.menu_button(self.cx.tr("project-open-recent"), |ui| {
+            open = self.recent_projects.menu_ui(ui, &self.cx);
+        });
+        if let Some(project) = open {
+            self.open_project(project);
+            ui.close_menu();
+        }
+        let close = egui::Button::new(self.cx.tr("project-close"));
+        if ui
+            .add_enabled(self.state.project.is_some(), close)
+            .clicked()
+        {
+            self.close_project();
+            ui.close_menu();
+        }
+    }
+
+    /// Maximize pane and distraction-free mode of the Window menu.
+    fn maximize_menu(&mut self, ui: &mut Ui) {
+        let entries = [
+            (false, "pane-maximize", MAXIMIZE_PANE_SHORTCUT),
+            (true, "pane-distraction-free", DISTRACTION_FREE_SHORTCUT),
+        ];
+        for (distraction_free, key, shortcut) in entries {
+            let selected = self.maximized.is_some() && self.distraction_free == distraction_free;
+            let button = egui::Button::new(self.cx.tr(key))
+                .selected(selected)
+                .shortcut_text(ui.ctx().format_shortcut(&shortcut));
+            if ui.add(button).clicked() {
+                self.toggle_maximized(distraction_free);
+                ui.close_menu();
+            }
+        }
+    }
+
+    fn edit_menu(&mut self, ui: &mut Ui) {
+        let (undo_name, redo_name) = {
+            let undo = self.cx.undo();
+            (
+                undo.undo_name().map(str::to_owned),
+                undo.redo_name().map(str::to_owned),
+            )
+        };
+        let undo_button = egui::Button::new(match &undo_name {
+            Some(name) => self
+                .cx
+                .tr_args("edit-undo-action", &[("action", &self.cx.tr(name))]),
+            None => self.cx.tr("edit-undo"),
+        })
+        .shortcut_text(ui.ctx().format_shortcut(&UNDO_SHORTCUT));
+        if ui.add_enabled(undo_name.is_some(), undo_button).clicked() {
+            self.step_history(false);
+            ui.close_menu();
+        }
+        let redo_button = egui::Button::new(match &redo_name {
+            Some(name) => self
+                .cx
+                .tr_args("edit-redo-action", &[("action", &self.cx.tr(name))]),
+            None => self.cx.tr("edit-redo"),
+        })
+        .shortcut_text(ui.ctx().format_shortcut(&REDO_SHORTCUT));
+        if ui.add_enabled(redo_name.is_some(), redo_button).clicked() {
+            self.step_history(true);
+            ui.close_menu();
+        }
+
+        ui.separator();
+        let mut grouped = self.cx.undo().group_interval.is_some();
+        if ui
+            .checkbox(&mut grouped, self.cx.tr("edit-group"))
+            .on_hover_text(self.cx.tr("edit-group-hint"))
+            .changed()
+        {
+            let mut undo = self.cx.undo();
+            undo.group_interval = grouped.then_some(crate::undo::DEFAULT_GROUP_INTERVAL);
+        }
+    }
+
+    /// Undo the last change, or redo the last undone one.
+    fn step_history(&mut self, redo: bool) {
+        let step = {
+            let mut undo = self.cx.undo();
+            let entry = if redo { undo.redo() } else { undo.undo() };
+            entry.map(|entry| {
+                let snapshot = if redo { &entry.after } else { &entry.before };
+                (entry.name.clone(), entry.target, snapshot.clone())
+            })
+        };
+        let Some((name, target, snapshot)) = step else {
+            return;
+        };
+        let verb = if redo { "redo" } else { "undo" };
+        debug!("{verb} {name:?} of {target:?}");
+        match target {
+            UndoTarget::Tab(tile_id) => match self.state.tabs.tiles.get_mut(tile_id) {
+                Some(Tile::Pane(tab)) => {
+                    if let Err(e) = tab.restore(&snapshot) {
+                        error!("Failed to {verb} {name:?}: {e}");
+                    }
+                }
+                _ => warn!("Can't {verb} {name:?}, the tab was closed"),
+            },
+            UndoTarget::Layout => match ron::from_str(&snapshot) {
+                Ok(layout) => self.state.restore_layout(layout),
+                Err(e) => error!("Failed to {verb} {name:?}: {e}"),
+            },
+        }
+        // Taken again at the end of the frame.
+        self.layout_snapshot = None;
+    }
+
+    /// Push an undo entry for a change of the tile tree made this frame, if there was one.
+    /// `refresh` takes a new snapshot for a change that doesn't get an entry of its own.
+    fn record_layout(&mut self, edit: Option<&'static str>, refresh: bool) {
+        if edit.is_none() && !refresh && self.layout_snapshot.is_some() {
+            return;
+        }
+        profile_function!();
+        let after = match ron::to_string(&self.state.tabs) {
+            Ok(after) => after,
+            Err(e) => {
+                error!("Failed to record the layout for undo: {e}");
+                return;
+            }
+        };
+        if let (Some(name), Some(before)) = (edit, self.layout_snapshot.take()) {
+            match self.with_current_tabs(before) {
+                Ok(before) => self
+                    .cx
+                    .undo()
+                    .push(name, UndoTarget::Layout, before, after.clone()),
+                Err(e) => error!("Failed to record the layout for undo: {e}"),
+            }
+        }
+        self.cx.undo().take_edited_panes();
+        self.layout_snapshot = Some(after);
+    }
+
+    /// Bring the tabs edited since the snapshot was taken up to date, for when a closed tab is
+    /// restored. Done here rather than on every edit, which would serialize the tree per keystroke.
+    fn with_current_tabs(&self, snapshot: String) -> Result<String, String> {
+        let mut undo = self.cx.undo();
+        let edited = undo.take_edited_panes();
+        if edited.is_empty() {
+            return Ok(snapshot);
+        }
+        let mut layout: egui_tiles::Tree<Tab> =
+            ron::from_str(&snapshot).map_err(|e| e.to_string())?;
+        for tile_id in edited {
+            if let (Some(Tile::Pane(tab)), Some(state)) =
+                (layout.tiles.get_mut(tile_id), undo.tab_state(tile_id))
+            {
+                tab.restore(state).map_err(|e| e.to_string())?;
+            }
+        }
+        ron::to_string(&layout).map_err(|e| e.to_string())
+    }
+
+    /// Replace the whole state, which also starts a new undo history.
+    fn set_state(&mut self, ctx: &egui::Context, state: State) {
+        let previous = std::mem::replace(&mut self.state, state);
+        // Sections registered by the embedding code are not part of the state.
+        self.state.navigator.adopt_sections(previous.navigator);
+        self.state.apply_settings(ctx);
+        self.state.attach(
+            &self.cx,
+            #[cfg(not(target_arch = "wasm32"))]
+            &self.event_collector,
+        );
+        self.cx.undo().clear();
+        self.layout_snapshot = None;
+    }
+
+    fn side_panel(&mut self, ui: &mut Ui) {
+        let mut env = SectionEnv {
+            cx: &mut self.cx,
+            tree: &mut self.state.tabs,
+            behavior: &mut self.state.tabs_behavior,
+            inspector: &mut self.state.inspector,
+        };
+        self.state.navigator.ui(ui, &mut env);
+    }
+}
+
+impl eframe::App for TemplateApp {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        // Put what the user was working on into the crash report, the panic hook prepared the rest.
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Err(panic) = crate::crash::catch_frame(|| self.show(ctx, frame)) {
+            crate::crash::write_frame_crash(ron::to_string(&self.state).ok());
+            std::panic::resume_unwind(panic);
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.show(ctx, frame);
+    }
+
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        eframe::set_value(storage, eframe::APP_KEY, &self.state);
+        // Keep what failed to load until the user decides about it, the rest is saved as usual.
+        if !self
+            .storage_notice
+            .as_ref()
+            .is_some_and(StorageNotice::blocks_saving)
+        {
+            self.save_state(storage);
+        }
+        if let Some(settings) = self.state.windows.settings_mut() {
+            settings.theme.save();
+        }
+
+        match ron::to_string(&self.recent_projects) {
+            Ok(serialized) => storage.set_string(&recent_projects_key(), serialized),
+            Err(e) => error!("Failed to serialize the recent projects: {e}"),
+        }
+        if let Some(project) = &self.state.project {
+            self.save_project_layout(project);
+        }
+        self.cx.diagnostics().saves += 1;
     }
 
     #[cfg(feature = "glow")]
@@ -278,4 +1181,250 @@ impl eframe::App for TemplateApp {
     }
 }
 
-impl State {}
+/// Why saved state could not be restored.
+#[derive(Debug)]
+pub enum LoadError {
+    Missing,
+    Decode(ron::error::SpannedError),
+    Invalid(&'static str),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Missing => write!(f, "there is no saved state"),
+            LoadError::Decode(e) => write!(f, "deserializing failed: {e}"),
+            LoadError::Invalid(reason) => write!(f, "saved state is invalid: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Decode state the same way [`TemplateApp::new`] does, without creating the app.
+///
+/// Used by the persistence tests and the fuzzer.
+pub fn check_saved_state(saved: &str) -> Result<(), LoadError> {
+    State::decode(saved).map(|_| ())
+}
+
+impl State {
+    /// Hand out what the deserialized parts of the state need at runtime.
+    fn attach(
+        &mut self,
+        cx: &Context,
+        #[cfg(not(target_arch = "wasm32"))] event_collector: &egui_tracing::EventCollector,
+    ) {
+        // Restore contexts for windows
+        for (window, _) in &mut self.windows.windows {
+            match window {
+                #[cfg(not(target_arch = "wasm32"))]
+                WindowKind::LogViewer(log_viewer) => {
+                    log_viewer.set_collector(event_collector.clone());
+                }
+                WindowKind::Notifications(notifications) => notifications.attach(cx),
+                WindowKind::Settings(settings) => cx.i18n().set_language(settings.language),
+                _ => {}
+            }
+        }
+
+        // Restore context for tabs
+        self.tabs_behavior.feed_cx(cx.clone());
+    }
+
+    /// Apply the persisted settings that live in the egui context, like the theme.
+    fn apply_settings(&mut self, ctx: &egui::Context) {
+        if let Some(settings) = self.windows.settings_mut() {
+            settings.attach(ctx);
+        }
+    }
+
+    fn load_layout(path: &std::path::Path) -> Result<egui_tiles::Tree<Tab>, String> {
+        let layout = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::decode_layout(&layout)
+    }
+
+    /// A tile tree as saved for projects and "Copy layout".
+    fn decode_layout(layout: &str) -> Result<egui_tiles::Tree<Tab>, String> {
+        let tabs: egui_tiles::Tree<Tab> = ron::from_str(layout).map_err(|e| e.to_string())?;
+        if let Some(root) = tabs.root() {
+            if tabs.tiles.get(root).is_none() {
+                return Err("root tile is missing".to_owned());
+            }
+        }
+        Ok(tabs)
+    }
+
+    fn decode(saved: &str) -> Result<Self, LoadError> {
+        let mut state: State = ron::from_str(saved).map_err(LoadError::Decode)?;
+
+        if let Some(root) = state.tabs.root() {
+            if state.tabs.tiles.get(root).is_none() {
+                return Err(LoadError::Invalid("root tile is missing"));
+            }
+        }
+
+        // State saved before a window was added doesn't have it.
+        state.windows.add_missing();
+
+        Ok(state)
+    }
+
+    /// Go back to an earlier tile tree, keeping the current state of the tabs that are in both.
+    fn restore_layout(&mut self, layout: egui_tiles::Tree<Tab>) {
+        let mut current = std::mem::replace(&mut self.tabs, layout);
+        for (tile_id, tile) in self.tabs.tiles.iter_mut() {
+            if let (Tile::Pane(tab), Some(Tile::Pane(current))) =
+                (tile, current.tiles.remove(*tile_id))
+            {
+                tab.kind = current.kind;
+                tab.failure = current.failure;
+            }
+        }
+    }
+
+    fn next_tab_nr(&self) -> usize {
+        self.tabs
+            .tiles
+            .iter()
+            .filter_map(|(_, tile)| match tile {
+                Tile::Pane(tab) => Some(tab.nr + 1),
+                Tile::Container(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Add a new tab of the given kind to a container and make it active.
+    fn add_tab(&mut self, container: TileId, kind: TabKindDiscriminants) {
+        let tab = kind.create_tab(self.next_tab_nr());
+        self.insert_tab(Some(container), tab);
+    }
+
+    /// Add the tab to a container and make it active, without one it goes next to the root.
+    fn insert_tab(&mut self, container: Option<TileId>, tab: Tab) -> TileId {
+        let tile_id = self.tabs.tiles.insert_pane(tab);
+        match container.and_then(|container| self.tabs.tiles.get_mut(container)) {
+            Some(Tile::Container(parent)) => {
+                parent.add_child(tile_id);
+                if let Container::Tabs(tabs) = parent {
+                    tabs.set_active(tile_id);
+                }
+            }
+            _ => {
+                let children = self.tabs.root().into_iter().chain([tile_id]).collect();
+                let root = self.tabs.tiles.insert_tab_tile(children);
+                if let Some(Tile::Container(Container::Tabs(tabs))) = self.tabs.tiles.get_mut(root)
+                {
+                    tabs.set_active(tile_id);
+                }
+                let tiles = std::mem::take(&mut self.tabs.tiles);
+                self.tabs = egui_tiles::Tree::new(self.tabs.id(), root, tiles);
+            }
+        }
+        tile_id
+    }
+
+    /// Where files dropped on the given pane go and the rect to highlight, picked like egui_tiles
+    /// picks where a dragged tile goes: of the pane's halves and the whole pane, the one whose
+    /// center is closest to the pointer. The whole pane means a new tab next to it.
+    fn drop_point(
+        &self,
+        pane: Option<TileId>,
+        pointer: Option<egui::Pos2>,
+    ) -> Option<(InsertionPoint, egui::Rect)> {
+        let pane = pane?;
+        let rect = self.tabs.tiles.rect(pane)?;
+        let pointer = pointer.unwrap_or(rect.center());
+        let (left, right) = rect.split_left_right_at_fraction(0.5);
+        let (top, bottom) = rect.split_top_bottom_at_fraction(0.5);
+        [
+            (ContainerInsertion::Tabs(usize::MAX), rect),
+            (ContainerInsertion::Horizontal(0), left),
+            (ContainerInsertion::Horizontal(usize::MAX), right),
+            (ContainerInsertion::Vertical(0), top),
+            (ContainerInsertion::Vertical(usize::MAX), bottom),
+        ]
+        .into_iter()
+        .min_by(|(_, a), (_, b)| {
+            let distance = |rect: &egui::Rect| pointer.distance_sq(rect.center());
+            distance(a).total_cmp(&distance(b))
+        })
+        .map(|(insertion, rect)| (InsertionPoint::new(pane, insertion), rect))
+    }
+
+    /// Add the tab next to the pane of the insertion point, into the pane's container if that
+    /// lays out its children the right way, else into a new container taking the pane's place.
+    fn insert_tab_at(&mut self, point: &InsertionPoint, tab: Tab) -> TileId {
+        let pane = point.parent_id;
+        let (kind, after) = match point.insertion {
+            ContainerInsertion::Horizontal(index) => (ContainerKind::Horizontal, index > 0),
+            ContainerInsertion::Vertical(index) => (ContainerKind::Vertical, index > 0),
+            _ => (ContainerKind::Tabs, true),
+        };
+        let tile_id = self.tabs.tiles.insert_pane(tab);
+        let parent = self.tabs.tiles.parent_of(pane);
+        match parent.and_then(|parent| self.tabs.tiles.get_mut(parent)) {
+            // A grid places its children itself.
+            Some(Tile::Container(container @ Container::Grid(_))) => container.add_child(tile_id),
+            Some(Tile::Container(container)) if container.kind() == kind => {
+                let children = match container {
+                    Container::Tabs(tabs) => &mut tabs.children,
+                    Container::Linear(linear) => &mut linear.children,
+                    Container::Grid(_) => unreachable!("handled above"),
+                };
+                let index = children
+                    .iter()
+                    .position(|child| *child == pane)
+                    .map_or(children.len(), |index| index + usize::from(after));
+                children.insert(index, tile_id);
+                if let Container::Tabs(tabs) = container {
+                    tabs.set_active(tile_id);
+                }
+            }
+            _ => {
+                let children = if after {
+                    vec![pane, tile_id]
+                } else {
+                    vec![tile_id, pane]
+                };
+                let container_id = match kind {
+                    ContainerKind::Horizontal => self.tabs.tiles.insert_horizontal_tile(children),
+                    ContainerKind::Vertical => self.tabs.tiles.insert_vertical_tile(children),
+                    _ => self.tabs.tiles.insert_tab_tile(children),
+                };
+                if let Some(Tile::Container(Container::Tabs(tabs))) =
+                    self.tabs.tiles.get_mut(container_id)
+                {
+                    tabs.set_active(tile_id);
+                }
+                // The new container takes the pane's place in its parent.
+                match parent.and_then(|parent| self.tabs.tiles.get_mut(parent)) {
+                    Some(Tile::Container(Container::Tabs(tabs))) => {
+                        for child in &mut tabs.children {
+                            if *child == pane {
+                                *child = container_id;
+                            }
+                        }
+                        if tabs.active == Some(pane) {
+                            tabs.set_active(container_id);
+                        }
+                    }
+                    Some(Tile::Container(Container::Linear(linear))) => {
+                        for child in &mut linear.children {
+                            if *child == pane {
+                                *child = container_id;
+                            }
+                        }
+                        linear.shares[container_id] = linear.shares[pane];
+                    }
+                    _ => {
+                        let tiles = std::mem::take(&mut self.tabs.tiles);
+                        self.tabs = egui_tiles::Tree::new(self.tabs.id(), container_id, tiles);
+                    }
+                }
+            }
+        }
+        tile_id
+    }
+}
@@ -0,0 +1,58 @@
+//! Information about the running build, embedded at compile time by `build.rs`.
+
+/// Name of the crate, from `Cargo.toml`.
+pub const NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Version of the crate, from `Cargo.toml`.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Short hash of the git commit the app was built from, `unknown` outside a git checkout.
+pub const GIT_HASH: &str = env!("GIT_HASH");
+
+/// UTC date of the build, `YYYY-MM-DD`. Follows `SOURCE_DATE_EPOCH` when set.
+pub const BUILD_DATE: &str = env!("BUILD_DATE");
+
+/// Target triple the app was compiled for.
+pub const TARGET: &str = env!("BUILD_TARGET");
+
+/// Version and commit, e.g. `0.1.0 (3f2a9c1)`.
+pub fn version() -> String {
+    format!("{VERSION} ({GIT_HASH})")
+}
+
+/// Enabled cargo features of this crate.
+pub fn features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "wgpu") {
+        features.push("wgpu");
+    }
+    if cfg!(feature = "glow") {
+        features.push("glow");
+    }
+    if cfg!(feature = "profiling") {
+        features.push("profiling");
+    }
+    if cfg!(feature = "accessibility") {
+        features.push("accessibility");
+    }
+    features
+}
+
+/// Text block for bug reports.
+pub fn diagnostics(renderer: Option<&str>) -> String {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    format!(
+        "{NAME} {}\n\
+        Built: {BUILD_DATE} ({profile})\n\
+        Target: {TARGET}\n\
+        Features: {}\n\
+        Renderer: {}\n",
+        version(),
+        features().join(", "),
+        renderer.unwrap_or("unknown"),
+    )
+}
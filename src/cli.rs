@@ -0,0 +1,89 @@
+use crate::app::StartupOptions;
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+/// Command-line arguments of the native binary.
+#[derive(Parser, Debug)]
+#[command(version, about = "eframe template", long_about = None)]
+pub struct Args {
+    /// Open with the tile layout from a RON file (Layout section → "Copy layout" produces one).
+    #[arg(long, value_name = "FILE")]
+    pub layout: Option<PathBuf>,
+
+    /// Use a separately persisted state, e.g. one per task or per screen.
+    #[arg(long, value_name = "NAME")]
+    pub workspace: Option<String>,
+
+    /// Open a project, a file or directory, with the layout saved next to it.
+    #[arg(long, value_name = "PATH")]
+    pub project: Option<PathBuf>,
+
+    /// Ignore the persisted state and start with the default one.
+    #[arg(long)]
+    pub reset_state: bool,
+
+    /// Log filter directives, e.g. `info,eframe_template=debug`. Defaults to `RUST_LOG`.
+    #[arg(long, value_name = "DIRECTIVES")]
+    pub log_filter: Option<String>,
+
+    /// Also write logs to this file.
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Initial window size.
+    #[arg(long, value_name = "WxH", value_parser = parse_window_size, default_value = "400x300")]
+    pub window_size: [f32; 2],
+
+    /// Start in fullscreen.
+    #[arg(long)]
+    pub fullscreen: bool,
+
+    /// Rendering backend, only the ones enabled with cargo features are available.
+    #[arg(long, value_enum)]
+    pub renderer: Option<Renderer>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Renderer {
+    #[cfg(feature = "glow")]
+    Glow,
+    #[cfg(feature = "wgpu")]
+    Wgpu,
+}
+
+impl From<Renderer> for eframe::Renderer {
+    fn from(renderer: Renderer) -> Self {
+        match renderer {
+            #[cfg(feature = "glow")]
+            Renderer::Glow => eframe::Renderer::Glow,
+            #[cfg(feature = "wgpu")]
+            Renderer::Wgpu => eframe::Renderer::Wgpu,
+        }
+    }
+}
+
+impl Args {
+    pub fn startup_options(&self) -> StartupOptions {
+        StartupOptions {
+            layout: self.layout.clone(),
+            workspace: self.workspace.clone(),
+            reset_state: self.reset_state,
+            route: None,
+            project: self.project.clone().map(crate::project::Project::new),
+        }
+    }
+}
+
+fn parse_window_size(s: &str) -> Result<[f32; 2], String> {
+    let (w, h) = s
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("expected WIDTHxHEIGHT, e.g. 800x600, got `{s}`"))?;
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|v| *v > 0.0)
+            .ok_or_else(|| format!("invalid window dimension `{v}`"))
+    };
+    Ok([parse(w)?, parse(h)?])
+}
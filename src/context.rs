@@ -1,10 +1,90 @@
+use crate::diagnostics::Diagnostics;
+use crate::i18n::Localizer;
+use crate::notifications::{NotificationAction, Notifications, NotifyLevel};
+use crate::status_bar::StatusBar;
+use crate::tasks::{TaskHandle, TaskId, Tasks};
+use crate::undo::UndoHistory;
+use egui::mutex::{Mutex, MutexGuard};
+use std::sync::Arc;
+
 #[derive(Clone)]
 pub struct Context {
-    // inner: Arc<...>
+    diagnostics: Arc<Mutex<Diagnostics>>,
+    notifications: Arc<Mutex<Notifications>>,
+    undo: Arc<Mutex<UndoHistory>>,
+    i18n: Arc<Mutex<Localizer>>,
+    status_bar: Arc<Mutex<StatusBar>>,
+    tasks: Arc<Mutex<Tasks>>,
 }
 
 impl Context {
     pub fn new() -> Self {
-        Context {}
+        Context {
+            diagnostics: Default::default(),
+            notifications: Default::default(),
+            undo: Default::default(),
+            i18n: Default::default(),
+            status_bar: Default::default(),
+            tasks: Default::default(),
+        }
+    }
+
+    pub fn diagnostics(&self) -> MutexGuard<'_, Diagnostics> {
+        self.diagnostics.lock()
+    }
+
+    pub fn notifications(&self) -> MutexGuard<'_, Notifications> {
+        self.notifications.lock()
+    }
+
+    pub fn undo(&self) -> MutexGuard<'_, UndoHistory> {
+        self.undo.lock()
+    }
+
+    pub fn i18n(&self) -> MutexGuard<'_, Localizer> {
+        self.i18n.lock()
+    }
+
+    pub fn status_bar(&self) -> MutexGuard<'_, StatusBar> {
+        self.status_bar.lock()
+    }
+
+    pub fn tasks(&self) -> MutexGuard<'_, Tasks> {
+        self.tasks.lock()
+    }
+
+    /// Run `work` off the UI thread, it is listed in the Tasks window with its result, see
+    /// [`crate::tasks`].
+    pub fn spawn_task(
+        &self,
+        name: impl Into<String>,
+        work: impl FnOnce(&TaskHandle) -> Result<String, String> + Send + 'static,
+    ) -> TaskId {
+        self.tasks().spawn(name.into(), work)
+    }
+
+    /// Text for `key` in the current language, see [`crate::i18n`].
+    pub fn tr(&self, key: &str) -> String {
+        self.i18n().tr(key)
+    }
+
+    /// Same as [`Context::tr`], with values for the `{ $name }` placeables.
+    pub fn tr_args(&self, key: &str, args: &[(&str, &str)]) -> String {
+        self.i18n().tr_args(key, args)
+    }
+
+    /// Show a toast, it is also kept in the Notifications window.
+    pub fn notify(&self, level: NotifyLevel, text: impl Into<String>) {
+        self.notifications().push(level, text.into(), Vec::new());
+    }
+
+    /// Show a toast with buttons.
+    pub fn notify_with_actions(
+        &self,
+        level: NotifyLevel,
+        text: impl Into<String>,
+        actions: Vec<NotificationAction>,
+    ) {
+        self.notifications().push(level, text.into(), actions);
     }
 }
@@ -0,0 +1,370 @@
+//! Crash reports: when a panic is not caught, the app state and the report of the panic hook go
+//! to a file in the storage directory, and the next start offers to restore the state or export
+//! the report.
+//!
+//! Panics caught with [`catch`], like in a tab or a task, are shown where they happened instead,
+//! without writing anything.
+//!
+//! Native only: on the web panics abort without unwinding and there is no file system.
+
+use crate::prelude::*;
+use egui::{Id, ScrollArea, TextEdit};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// How many of the most recent log lines go into a report.
+const LOG_LINES: usize = 50;
+
+/// What happens to a panic on the current thread once it unwound.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Catcher {
+    /// Nothing catches it, the hook writes the report if the app goes down with it.
+    Nobody,
+    /// [`catch_frame`], the report is written with the app state.
+    Frame,
+    /// [`catch`], the report is only shown.
+    Handled,
+}
+
+thread_local! {
+    static CATCHER: Cell<Catcher> = const { Cell::new(Catcher::Nobody) };
+    /// Report of the panic unwinding on this thread, taken by whoever catches it.
+    static PENDING: RefCell<Option<CrashReport>> = const { RefCell::new(None) };
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CrashReport {
+    pub version: String,
+    /// Unix time of the panic, in seconds.
+    pub time: u64,
+    pub thread: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub log: Vec<String>,
+    /// Serialized app state, when the panic happened while drawing the UI.
+    pub state: Option<String>,
+}
+
+impl CrashReport {
+    /// The backtrace of a panic that will be caught is only captured when `RUST_BACKTRACE` asks
+    /// for it, which is much cheaper.
+    fn new(info: &PanicHookInfo<'_>, log: &RecentLog, handled: bool) -> Self {
+        let backtrace = if handled {
+            std::backtrace::Backtrace::capture()
+        } else {
+            std::backtrace::Backtrace::force_capture()
+        };
+        Self {
+            location: info.location().map(|location| location.to_string()),
+            backtrace: backtrace.to_string(),
+            log: log.lines(),
+            ..Self::caught(panic_message(info.payload()))
+        }
+    }
+
+    /// Only what is known where the panic was caught, when the hook isn't installed.
+    fn caught(message: String) -> Self {
+        Self {
+            version: crate::build_info::version(),
+            time: crate::storage::unix_secs(),
+            thread: std::thread::current()
+                .name()
+                .unwrap_or("<unnamed>")
+                .to_owned(),
+            message,
+            location: None,
+            backtrace: String::new(),
+            log: Vec::new(),
+            state: None,
+        }
+    }
+
+    /// Human readable report, e.g. for an issue.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "Panic in thread '{}' at {}:\n{}\n\n{}\nBacktrace:\n{}\n\nRecent log:\n",
+            self.thread,
+            self.location.as_deref().unwrap_or("unknown location"),
+            self.message,
+            crate::build_info::diagnostics(None),
+            self.backtrace.trim_end(),
+        );
+        for line in &self.log {
+            text.push_str(line);
+            text.push('\n');
+        }
+        if let Some(state) = &self.state {
+            text.push_str("\nState:\n");
+            text.push_str(state);
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// The text passed to `panic!`, if there was one.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_owned())
+}
+
+fn path() -> Option<PathBuf> {
+    eframe::storage_dir(crate::APP_NAME).map(|dir| dir.join("crash.ron"))
+}
+
+fn write(report: &CrashReport) {
+    let Some(path) = path() else {
+        return;
+    };
+    let result = ron::ser::to_string_pretty(report, Default::default())
+        .map_err(|e| e.to_string())
+        .and_then(|ron| {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            std::fs::write(&path, ron).map_err(|e| e.to_string())
+        });
+    // Logging may be what panicked, so don't go through it.
+    match result {
+        Ok(()) => eprintln!("Crash report written to {}", path.display()),
+        Err(e) => eprintln!("Failed to write crash report to {}: {e}", path.display()),
+    }
+}
+
+/// The most recent log lines, for crash reports.
+///
+/// Also a `tracing` layer collecting them. Like [`crate::notifications::ToastLayer`] it never
+/// waits for the lock, the hook may run while a log message is being recorded.
+#[derive(Clone, Default)]
+pub struct RecentLog {
+    lines: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl RecentLog {
+    fn lines(&self) -> Vec<String> {
+        match self.lines.try_lock() {
+            Ok(lines) => lines.iter().cloned().collect(),
+            Err(_) => vec!["<log unavailable, it was being written>".to_owned()],
+        }
+    }
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for RecentLog {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut message = MessageVisitor(String::new());
+        event.record(&mut message);
+        // UTC, the time zone isn't known without another dependency.
+        let millis_today = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            % (24 * 60 * 60 * 1000);
+        let line = format!(
+            "{:02}:{:02}:{:02}.{:03} {:>5} {}: {}",
+            millis_today / 3_600_000,
+            millis_today / 60_000 % 60,
+            millis_today / 1000 % 60,
+            millis_today % 1000,
+            event.metadata().level(),
+            event.metadata().target(),
+            message.0,
+        );
+        let Ok(mut lines) = self.lines.try_lock() else {
+            return;
+        };
+        if lines.len() == LOG_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+struct MessageVisitor(String);
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, "{value:?}");
+        }
+    }
+}
+
+/// Prepare a crash report on every panic, before the default hook runs.
+///
+/// Panics on the main thread that nothing catches are written right away, the others are left
+/// for [`catch`] or [`catch_frame`].
+pub fn install_hook(log: RecentLog) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let catcher = CATCHER.get();
+        // Other threads going down don't take the app with them.
+        let is_main = std::thread::current().name() == Some("main");
+        if catcher != Catcher::Nobody || is_main {
+            let report = CrashReport::new(info, &log, catcher == Catcher::Handled);
+            match catcher {
+                Catcher::Nobody => write(&report),
+                Catcher::Frame | Catcher::Handled => PENDING.set(Some(report)),
+            }
+        }
+        previous(info);
+    }));
+}
+
+fn catch_as<R>(catcher: Catcher, f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    let outer = CATCHER.replace(catcher);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    CATCHER.set(outer);
+    result
+}
+
+/// Run `f`, turning a panic into its report. It is not a crash, so nothing is written.
+pub(crate) fn catch<R>(f: impl FnOnce() -> R) -> Result<R, CrashReport> {
+    catch_as(Catcher::Handled, f).map_err(|payload| {
+        PENDING
+            .take()
+            .unwrap_or_else(|| CrashReport::caught(panic_message(&*payload)))
+    })
+}
+
+/// Run a frame, a panic is returned to be passed on with [`write_frame_crash`].
+pub(crate) fn catch_frame(f: impl FnOnce()) -> std::thread::Result<()> {
+    catch_as(Catcher::Frame, f)
+}
+
+/// Write the report of the panic caught by [`catch_frame`], with the app state.
+pub(crate) fn write_frame_crash(state: Option<String>) {
+    if let Some(mut report) = PENDING.take() {
+        report.state = state;
+        write(&report);
+    }
+}
+
+/// Report left by the previous run, if it crashed.
+pub(crate) fn previous_report() -> Option<CrashReport> {
+    let path = path()?;
+    let ron = std::fs::read_to_string(&path).ok()?;
+    match ron::from_str(&ron) {
+        Ok(report) => Some(report),
+        Err(e) => {
+            warn!("Ignoring unreadable crash report {}: {e}", path.display());
+            discard();
+            None
+        }
+    }
+}
+
+fn discard() {
+    if let Some(path) = path() {
+        if let Err(e) = std::fs::remove_file(&path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!("Failed to remove crash report {}: {e}", path.display());
+            }
+        }
+    }
+}
+
+pub(crate) enum CrashChoice {
+    /// Replace the state with the one from the report.
+    Restore(String),
+    Dismiss,
+}
+
+/// Shown on start after a crash.
+pub(crate) struct CrashDialog {
+    report: CrashReport,
+    show_report: bool,
+    exported: Option<Result<String, String>>,
+}
+
+impl CrashDialog {
+    pub(crate) fn new(report: CrashReport) -> Self {
+        Self {
+            report,
+            show_report: false,
+            exported: None,
+        }
+    }
+
+    /// Once the user made a choice, the report is removed and the choice returned.
+    pub(crate) fn show(&mut self, ctx: &egui::Context, cx: &Context) -> Option<CrashChoice> {
+        let mut choice = None;
+        egui::Modal::new(Id::new("crash_dialog")).show(ctx, |ui| {
+            ui.set_width(480.0);
+            ui.heading(cx.tr("crash-title"));
+            ui.label(cx.tr_args(
+                "crash-message",
+                &[
+                    ("version", &self.report.version),
+                    ("message", &self.report.message),
+                ],
+            ));
+            if let Some(location) = &self.report.location {
+                ui.weak(location);
+            }
+
+            ui.checkbox(&mut self.show_report, cx.tr("crash-show-report"));
+            if self.show_report {
+                let mut text = self.report.to_text();
+                ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    ui.add(
+                        TextEdit::multiline(&mut text)
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .interactive(false),
+                    );
+                });
+            }
+            match &self.exported {
+                Some(Ok(location)) => {
+                    ui.label(cx.tr_args("crash-exported", &[("location", location)]));
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        cx.tr_args("crash-export-failed", &[("error", e)]),
+                    );
+                }
+                None => {}
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if let Some(state) = &self.report.state {
+                    if ui
+                        .button(cx.tr("crash-restore"))
+                        .on_hover_text(cx.tr("crash-restore-hint"))
+                        .clicked()
+                    {
+                        choice = Some(CrashChoice::Restore(state.clone()));
+                    }
+                }
+                if ui.button(cx.tr("crash-export")).clicked() {
+                    let text = self.report.to_text();
+                    self.exported = Some(crate::storage::export(cx, "crash", "txt", &text));
+                }
+                if ui.button(cx.tr("crash-copy")).clicked() {
+                    ui.ctx().copy_text(self.report.to_text());
+                }
+                if ui.button(cx.tr("crash-dismiss")).clicked() {
+                    choice = Some(CrashChoice::Dismiss);
+                }
+            });
+        });
+        if choice.is_some() {
+            discard();
+        }
+        choice
+    }
+}
@@ -0,0 +1,108 @@
+use crate::tabs::Tab;
+use egui::util::History;
+use egui::RepaintCause;
+use egui_tiles::Tile;
+
+/// Per-frame numbers collected by the app and shown in the Debug window.
+pub struct Diagnostics {
+    /// CPU time spent per frame, in seconds.
+    pub frame_times: History<f32>,
+    /// What caused the current frame to be painted.
+    pub repaint_causes: Vec<RepaintCause>,
+    pub tiles: TileCounts,
+    /// Size in bytes of the serialized state on the last `save`.
+    pub last_save_size: Option<usize>,
+    /// Why the last `save` failed, if it did.
+    pub last_save_error: Option<String>,
+    /// Counts calls to `save`, what is stored may have changed when it goes up.
+    pub saves: u64,
+    /// Rendering backend and graphics adapter, once known.
+    pub renderer: Option<String>,
+    /// Commit and build date shown in the About window, the ones of this build unless set.
+    pub git_hash: String,
+    pub build_date: String,
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct TileCounts {
+    pub panes: usize,
+    pub containers: usize,
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self {
+            frame_times: History::new(0..300, 2.0),
+            repaint_causes: Vec::new(),
+            tiles: Default::default(),
+            last_save_size: None,
+            last_save_error: None,
+            saves: 0,
+            renderer: None,
+            git_hash: crate::build_info::GIT_HASH.to_owned(),
+            build_date: crate::build_info::BUILD_DATE.to_owned(),
+        }
+    }
+}
+
+impl Diagnostics {
+    pub(crate) fn on_frame(
+        &mut self,
+        ctx: &egui::Context,
+        frame: &eframe::Frame,
+        tree: &egui_tiles::Tree<Tab>,
+    ) {
+        let now = ctx.input(|i| i.time);
+        if let Some(cpu_usage) = frame.info().cpu_usage {
+            self.frame_times.add(now, cpu_usage);
+        }
+        self.repaint_causes = ctx.repaint_causes();
+        if self.renderer.is_none() {
+            self.renderer = renderer_info(frame);
+        }
+
+        let mut tiles = TileCounts::default();
+        for (_, tile) in tree.tiles.iter() {
+            match tile {
+                Tile::Pane(_) => tiles.panes += 1,
+                Tile::Container(_) => tiles.containers += 1,
+            }
+        }
+        self.tiles = tiles;
+    }
+
+    pub fn mean_frame_time(&self) -> f32 {
+        self.frame_times.average().unwrap_or_default()
+    }
+}
+
+impl TileCounts {
+    pub fn total(&self) -> usize {
+        self.panes + self.containers
+    }
+}
+
+fn renderer_info(frame: &eframe::Frame) -> Option<String> {
+    #[cfg(feature = "wgpu")]
+    if let Some(render_state) = frame.wgpu_render_state() {
+        let info = render_state.adapter.get_info();
+        return Some(format!(
+            "wgpu {:?}: {} ({:?}), driver {} {}",
+            info.backend, info.name, info.device_type, info.driver, info.driver_info
+        ));
+    }
+    #[cfg(feature = "glow")]
+    if let Some(gl) = frame.gl() {
+        use eframe::glow::HasContext as _;
+        // SAFETY: plain queries, the context is current while a frame is painted.
+        let (renderer, version) = unsafe {
+            (
+                gl.get_parameter_string(eframe::glow::RENDERER),
+                gl.get_parameter_string(eframe::glow::VERSION),
+            )
+        };
+        return Some(format!("glow: {renderer}, {version}"));
+    }
+    let _ = frame;
+    None
+}
@@ -0,0 +1,114 @@
+//! Files dropped on the window open in a new tab of the kind that declared them with
+//! [`crate::tab_viewer::TabUi::file_types`].
+//!
+//! Like a dragged tile, the file goes into a new tab next to the pane it was dropped on, or splits
+//! that pane when dropped near one of its sides. Dropped elsewhere it goes into the root
+//! container. While files are dragged over the window the target is highlighted.
+
+use crate::prelude::*;
+use egui::{Align2, Color32, FontId, LayerId, Order, Rect, Stroke, StrokeKind};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Files a kind of tab opens, matched by extension or MIME type.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileTypes {
+    /// Without the dot, compared case-insensitively.
+    pub extensions: &'static [&'static str],
+    /// Either exact or a whole category like `text/*`.
+    pub mime_types: &'static [&'static str],
+}
+
+impl FileTypes {
+    pub const NONE: Self = Self {
+        extensions: &[],
+        mime_types: &[],
+    };
+
+    /// Natively the MIME type is usually empty, on the web the name may be.
+    pub fn matches(&self, name: &str, mime: &str) -> bool {
+        let extension = std::path::Path::new(name)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let extension_matches = extension.is_some_and(|extension| {
+            self.extensions
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(&extension))
+        });
+        let mime_matches = !mime.is_empty()
+            && self
+                .mime_types
+                .iter()
+                .any(|known| match known.strip_suffix('*') {
+                    Some(category) => mime.starts_with(category),
+                    None => mime == *known,
+                });
+        extension_matches || mime_matches
+    }
+}
+
+/// A dropped file with its content, handed to [`crate::tab_viewer::TabUi::open_file`].
+pub struct OpenedFile {
+    pub name: String,
+    /// Only natively.
+    pub path: Option<PathBuf>,
+    /// Empty if unknown.
+    pub mime: String,
+    pub bytes: Arc<[u8]>,
+}
+
+impl OpenedFile {
+    /// Read what egui got, the browser hands over the content, natively only the path.
+    pub(crate) fn read(dropped: &egui::DroppedFile) -> Result<Self, String> {
+        let bytes = match (&dropped.bytes, &dropped.path) {
+            (Some(bytes), _) => bytes.clone(),
+            (None, Some(path)) => std::fs::read(path).map_err(|e| e.to_string())?.into(),
+            (None, None) => return Err("the file has no content".to_owned()),
+        };
+        Ok(Self {
+            name: dropped_name(dropped.path.as_ref(), &dropped.name),
+            path: dropped.path.clone(),
+            mime: dropped.mime.clone(),
+            bytes,
+        })
+    }
+
+    pub fn text(&self) -> Result<&str, String> {
+        std::str::from_utf8(&self.bytes).map_err(|e| format!("not UTF-8 text: {e}"))
+    }
+}
+
+/// egui leaves the name empty natively, and the path empty on the web.
+pub(crate) fn dropped_name(path: Option<&PathBuf>, name: &str) -> String {
+    match path.and_then(|path| path.file_name()) {
+        Some(file_name) => file_name.to_string_lossy().into_owned(),
+        None => name.to_owned(),
+    }
+}
+
+/// Highlight where the hovered files will be opened, `rect` is the target pane or half of it.
+pub(crate) fn hover_ui(ctx: &egui::Context, cx: &Context, rect: Rect, unsupported: Option<&str>) {
+    let painter = ctx.layer_painter(LayerId::new(Order::Foreground, egui::Id::new("file_drop")));
+    let visuals = ctx.style().visuals.clone();
+    let (color, text) = match unsupported {
+        None => (visuals.selection.stroke.color, cx.tr("file-drop-here")),
+        Some(name) => (
+            visuals.error_fg_color,
+            cx.tr_args("file-drop-unsupported", &[("name", name)]),
+        ),
+    };
+    painter.rect(
+        rect.shrink(2.0),
+        4.0,
+        Color32::from_black_alpha(96),
+        Stroke::new(2.0, color),
+        StrokeKind::Inside,
+    );
+    painter.text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        text,
+        FontId::proportional(18.0),
+        color,
+    );
+}
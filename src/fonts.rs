@@ -0,0 +1,381 @@
+//! Font files, the bundled fonts, the fallback order of the font families and the UI zoom,
+//! edited in the Settings window.
+//!
+//! egui already zooms with Ctrl +/-/0, the zoom is persisted from the egui context every frame.
+
+use crate::prelude::*;
+use egui::{Button, FontData, FontDefinitions, FontFamily, WidgetInfo, WidgetType};
+use std::sync::Arc;
+
+/// Sample covering the scripts the fallbacks are usually added for.
+const PREVIEW_TEXT: &str = "The quick brown fox 0123 ⚠ — 日本語 中文 한국어";
+
+/// Fonts built into the app, available in the families on every platform.
+const BUNDLED_FONTS: &[(&str, &[u8])] = &[(
+    "DejaVu Sans Mono",
+    include_bytes!("../assets/fonts/DejaVuSansMono.ttf"),
+)];
+
+/// Where CJK fonts are usually installed, the first one found is offered as a fallback.
+#[cfg(not(target_arch = "wasm32"))]
+const SYSTEM_CJK_FONTS: &[&str] = &[
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+    "/System/Library/Fonts/PingFang.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\malgun.ttf",
+];
+
+/// A TTF/OTF file loaded from disk.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FontFile {
+    /// Name used in the font families.
+    pub name: String,
+    pub path: std::path::PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct FontSettings {
+    pub files: Vec<FontFile>,
+    /// Font names in fallback order, a glyph comes from the first font that has it.
+    pub proportional: Vec<String>,
+    pub monospace: Vec<String>,
+    pub zoom: f32,
+    /// Why files could not be loaded the last time the fonts were applied.
+    #[serde(skip)]
+    errors: Vec<String>,
+    #[serde(skip)]
+    new_file: FontFile,
+}
+
+impl Default for FontSettings {
+    fn default() -> Self {
+        let mut defaults = FontDefinitions::default();
+        let mut family = |family| defaults.families.remove(&family).unwrap_or_default();
+        Self {
+            files: Vec::new(),
+            proportional: family(FontFamily::Proportional),
+            monospace: family(FontFamily::Monospace),
+            zoom: 1.0,
+            errors: Vec::new(),
+            new_file: FontFile::default(),
+        }
+    }
+}
+
+impl FontSettings {
+    /// Load the font files and set the fonts and zoom of the egui context.
+    pub fn apply(&mut self, ctx: &egui::Context) {
+        let mut definitions = definitions();
+        self.errors.clear();
+        for file in &self.files {
+            match load(file) {
+                Ok(data) => {
+                    definitions
+                        .font_data
+                        .insert(file.name.clone(), Arc::new(data));
+                }
+                Err(e) => {
+                    warn!("Failed to load font {}: {e}", file.path.display());
+                    self.errors
+                        .push(format!("{} ({}): {e}", file.name, file.path.display()));
+                }
+            }
+        }
+        for (family, names) in [
+            (FontFamily::Proportional, &self.proportional),
+            (FontFamily::Monospace, &self.monospace),
+        ] {
+            let names: Vec<String> = names
+                .iter()
+                .filter(|name| definitions.font_data.contains_key(*name))
+                .cloned()
+                .collect();
+            // Keep egui's defaults rather than end up without any font.
+            if !names.is_empty() {
+                definitions.families.insert(family, names);
+            }
+        }
+        ctx.set_fonts(definitions);
+        ctx.set_zoom_factor(self.zoom);
+    }
+
+    /// Names of all fonts that can go into a family.
+    fn available(&self) -> Vec<String> {
+        definitions()
+            .font_data
+            .into_keys()
+            .chain(self.files.iter().map(|file| file.name.clone()))
+            .collect()
+    }
+
+    /// The Fonts page of the Settings window.
+    pub fn ui(&mut self, ui: &mut Ui, cx: &Context) {
+        let mut changed = false;
+
+        ui.horizontal(|ui| {
+            ui.label(cx.tr("fonts-zoom"));
+            egui::gui_zoom::zoom_menu_buttons(ui);
+            ui.label(format!("{:.0} %", ui.ctx().zoom_factor() * 100.0));
+        });
+        let ctx = ui.ctx().clone();
+        let shortcut = |shortcut| ctx.format_shortcut(&shortcut);
+        ui.weak(cx.tr_args(
+            "fonts-zoom-shortcuts",
+            &[
+                ("in", &shortcut(egui::gui_zoom::kb_shortcuts::ZOOM_IN)),
+                ("out", &shortcut(egui::gui_zoom::kb_shortcuts::ZOOM_OUT)),
+                ("reset", &shortcut(egui::gui_zoom::kb_shortcuts::ZOOM_RESET)),
+            ],
+        ));
+        ui.separator();
+
+        ui.strong(cx.tr("fonts-files"));
+        let mut remove = None;
+        let remove_name = cx.tr("fonts-remove-file");
+        for (index, file) in self.files.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let button = ui.small_button("✖").on_hover_text(&remove_name);
+                button.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, &remove_name));
+                if button.clicked() {
+                    remove = Some(index);
+                }
+                ui.label(&file.name);
+                ui.weak(file.path.display().to_string());
+            });
+        }
+        if let Some(index) = remove {
+            let file = self.files.remove(index);
+            self.proportional.retain(|name| name != &file.name);
+            self.monospace.retain(|name| name != &file.name);
+            changed = true;
+        }
+        for error in &self.errors {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+        if cfg!(target_arch = "wasm32") {
+            ui.weak(cx.tr("fonts-files-unavailable"));
+        } else {
+            changed |= self.add_file_ui(ui, cx);
+        }
+        ui.separator();
+
+        let available = self.available();
+        egui::Grid::new("font_families")
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (key, names) in [
+                    ("fonts-proportional", &mut self.proportional),
+                    ("fonts-monospace", &mut self.monospace),
+                ] {
+                    ui.label(cx.tr(key));
+                    changed |= family_ui(ui, cx, key, names, &available);
+                    ui.end_row();
+                }
+            });
+        if ui.button(cx.tr("fonts-reset")).clicked() {
+            *self = Self {
+                zoom: self.zoom,
+                ..Self::default()
+            };
+            changed = true;
+        }
+        ui.separator();
+
+        ui.label(PREVIEW_TEXT);
+        ui.monospace(PREVIEW_TEXT);
+
+        if changed {
+            self.apply(ui.ctx());
+        }
+    }
+
+    /// Returns whether a file was added.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn add_file_ui(&mut self, ui: &mut Ui, cx: &Context) -> bool {
+        let mut added = false;
+        ui.horizontal(|ui| {
+            let name_hint = cx.tr("fonts-file-name");
+            let name = ui.add(
+                egui::TextEdit::singleline(&mut self.new_file.name)
+                    .hint_text(&name_hint)
+                    .desired_width(80.0),
+            );
+            name.widget_info(|| text_edit_info(&name_hint, &self.new_file.name));
+            let path_hint = cx.tr("fonts-file-path");
+            let mut path_text = self.new_file.path.display().to_string();
+            let path = ui.add(egui::TextEdit::singleline(&mut path_text).hint_text(&path_hint));
+            path.widget_info(|| text_edit_info(&path_hint, &path_text));
+            if path.changed() {
+                self.new_file.path = path_text.into();
+            }
+            let valid = !self.new_file.name.is_empty()
+                && !self.new_file.path.as_os_str().is_empty()
+                && !self.available().contains(&self.new_file.name);
+            if ui
+                .add_enabled(valid, Button::new(cx.tr("fonts-add-file")))
+                .clicked()
+            {
+                let file = std::mem::take(&mut self.new_file);
+                // Most likely wanted as a fallback for glyphs the default fonts lack.
+                self.proportional.push(file.name.clone());
+                self.files.push(file);
+                added = true;
+            }
+        });
+
+        let has_cjk = self.files.iter().any(|file| file.name == "CJK");
+        let system_cjk = SYSTEM_CJK_FONTS
+            .iter()
+            .map(std::path::Path::new)
+            .find(|path| path.exists());
+        if let (false, Some(path)) = (has_cjk, system_cjk) {
+            if ui
+                .button(cx.tr("fonts-add-cjk"))
+                .on_hover_text(cx.tr_args(
+                    "fonts-add-cjk-hint",
+                    &[("path", &path.display().to_string())],
+                ))
+                .clicked()
+            {
+                self.files.push(FontFile {
+                    name: "CJK".to_owned(),
+                    path: path.to_owned(),
+                });
+                self.proportional.push("CJK".to_owned());
+                self.monospace.push("CJK".to_owned());
+                added = true;
+            }
+        }
+        added
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn add_file_ui(&mut self, _ui: &mut Ui, _cx: &Context) -> bool {
+        false
+    }
+}
+
+/// egui's default fonts and the bundled ones, without the files from disk.
+fn definitions() -> FontDefinitions {
+    let mut definitions = FontDefinitions::default();
+    for (name, bytes) in BUNDLED_FONTS {
+        definitions
+            .font_data
+            .insert((*name).to_owned(), Arc::new(FontData::from_static(bytes)));
+    }
+    definitions
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load(file: &FontFile) -> Result<FontData, String> {
+    let bytes = std::fs::read(&file.path).map_err(|e| e.to_string())?;
+    Ok(FontData::from_owned(bytes))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load(_file: &FontFile) -> Result<FontData, String> {
+    Err("no file system on the web".to_owned())
+}
+
+/// Accessible name for a text field that only has a hint.
+fn text_edit_info(label: &str, text: &str) -> WidgetInfo {
+    let mut info = WidgetInfo::new(WidgetType::TextEdit);
+    info.label = Some(label.to_owned());
+    info.current_text_value = Some(text.to_owned());
+    info
+}
+
+/// Edit of the font list of a family, applied after the list is shown.
+enum FamilyAction {
+    Swap(usize, usize),
+    Remove(usize),
+}
+
+/// Reorder, remove and add the fonts of a family, returns whether it changed.
+fn family_ui(
+    ui: &mut Ui,
+    cx: &Context,
+    id: &str,
+    names: &mut Vec<String>,
+    available: &[String],
+) -> bool {
+    let mut changed = false;
+    ui.vertical(|ui| {
+        let mut action = None;
+        let len = names.len();
+        let up_name = cx.tr("fonts-move-up");
+        let down_name = cx.tr("fonts-move-down");
+        let remove_name = cx.tr("fonts-remove-font");
+        for (index, name) in names.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let enabled = index > 0;
+                let up = ui
+                    .add_enabled(enabled, Button::new("⏶").small())
+                    .on_hover_text(&up_name);
+                up.widget_info(|| WidgetInfo::labeled(WidgetType::Button, enabled, &up_name));
+                if up.clicked() {
+                    action = Some(FamilyAction::Swap(index, index - 1));
+                }
+
+                let enabled = index + 1 < len;
+                let down = ui
+                    .add_enabled(enabled, Button::new("⏷").small())
+                    .on_hover_text(&down_name);
+                down.widget_info(|| WidgetInfo::labeled(WidgetType::Button, enabled, &down_name));
+                if down.clicked() {
+                    action = Some(FamilyAction::Swap(index, index + 1));
+                }
+
+                // Removing the last font would make egui fall back to its defaults anyway.
+                let enabled = len > 1;
+                let remove = ui
+                    .add_enabled(enabled, Button::new("✖").small())
+                    .on_hover_text(&remove_name);
+                remove
+                    .widget_info(|| WidgetInfo::labeled(WidgetType::Button, enabled, &remove_name));
+                if remove.clicked() {
+                    action = Some(FamilyAction::Remove(index));
+                }
+                ui.label(name);
+            });
+        }
+        match action {
+            Some(FamilyAction::Swap(a, b)) => {
+                names.swap(a, b);
+                changed = true;
+            }
+            Some(FamilyAction::Remove(index)) => {
+                names.remove(index);
+                changed = true;
+            }
+            None => {}
+        }
+
+        let missing: Vec<&String> = available
+            .iter()
+            .filter(|name| !names.contains(name))
+            .collect();
+        if !missing.is_empty() {
+            let add_name = cx.tr("fonts-add-fallback");
+            egui::ComboBox::from_id_salt(("add_font", id))
+                .selected_text(&add_name)
+                .show_ui(ui, |ui| {
+                    for name in missing {
+                        if ui.selectable_label(false, name).clicked() {
+                            names.push(name.clone());
+                            changed = true;
+                        }
+                    }
+                })
+                .response
+                .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, true, &add_name));
+        }
+    });
+    changed
+}
@@ -0,0 +1,325 @@
+# German.
+
+## Menu bar
+menu-file = Datei
+menu-edit = Bearbeiten
+menu-window = Fenster
+menu-help = Hilfe
+menu-theme = Design
+menu-quit = Beenden
+menu-reset-mem = Speicher zurücksetzen
+side-panel-toggle = Seitenleiste ein-/ausblenden
+
+## Side panel
+side-panel-navigator = Navigator
+side-panel-choose-sections = Seitenleisten-Bereiche auswählen
+side-panel-move-up = Nach oben
+side-panel-move-down = Nach unten
+side-panel-hide = Ausblenden
+section-outline = Gliederung
+section-layout = Layout
+
+## Edit menu
+edit-undo = Rückgängig
+edit-undo-action = { $action } rückgängig machen
+edit-redo = Wiederholen
+edit-redo-action = { $action } wiederholen
+edit-group = Schnelle Änderungen zusammenfassen
+edit-group-hint = Gleichartige Änderungen kurz nacheinander gemeinsam rückgängig machen
+
+## Undo history entries
+action-add-tab = Tab hinzufügen
+action-close-tab = Tab schließen
+action-move-tile = Kachel verschieben
+action-resize = Größe ändern
+action-show-hide-tile = Kachel ein-/ausblenden
+action-change-container-kind = Containerart ändern
+action-select-tab = Tab auswählen
+action-rename-tab = Tab umbenennen
+action-remove-tile = Kachel entfernen
+action-edit-text = Text bearbeiten
+action-change-value = Wert ändern
+action-open-file = Datei öffnen
+
+## Theme menu
+theme-system = System
+theme-light = Hell
+theme-dark = Dunkel
+
+## Confirm exit
+confirm-exit-title = Beenden bestätigen
+confirm-exit-yes = Ja
+confirm-exit-no = Nein
+
+## Windows
+window-about = Über
+window-settings = Einstellungen
+window-settings-hint = Konfigurationsfenster öffnen
+window-log-viewer = Protokoll
+window-debug = Debug
+window-notifications = Benachrichtigungen
+window-tasks = Aufgaben
+
+## Settings
+settings-general = Allgemein
+settings-theme = Design
+settings-fonts = Schriften
+settings-language = Sprache
+
+## Tabs
+tab-a = Tab A
+tab-b = Tab B
+tab-add = Tab hinzufügen
+tab-failed = { $title } funktioniert nicht mehr
+tab-reset = Tab-Zustand zurücksetzen
+tab-reset-hint = Den Tab durch einen neuen derselben Art ersetzen
+tab-close = Schließen
+tab-copy-report = Bericht kopieren
+tab-new = Neu: { $kind }
+tab-log = Protokollieren
+tab-a-text = Text:
+tab-a-characters = { $count } Zeichen
+tab-b-value = Wert
+tab-b-slider-value = Wert des Schiebereglers
+tab-b-notify = Benachrichtigen
+tab-b-slider-at = Der Schieberegler steht auf { $value }
+tab-b-count-primes = Primzahlen zählen
+tab-b-count-primes-task = Primzahlen unter { $limit } zählen
+tab-b-primes = { $count } Primzahlen
+
+## Status bar
+status-no-tab = Kein Tab fokussiert
+status-tasks = Aufgaben: { $count }
+status-log = { $warnings } Warnungen, { $errors } Fehler
+status-log-hint = Warnungen und Fehler, seit das Protokoll zuletzt von hier geöffnet wurde
+status-frame-time = { $ms } ms/Frame
+
+## Tasks window
+tasks-running = { $count } laufend
+tasks-clear-finished = Beendete entfernen
+tasks-none = Noch keine Aufgaben
+tasks-cancel = Abbrechen
+tasks-cancelled = Abgebrochen
+tasks-elapsed = { $seconds } s
+
+## Projects
+project-open = Öffnen…
+project-open-file = Datei öffnen…
+project-open-folder = Ordner öffnen…
+project-open-recent = Zuletzt geöffnet
+project-open-title = Projekt öffnen
+project-open-button = Öffnen
+project-cancel = Abbrechen
+project-close = Projekt schließen
+project-path-hint = Pfad einer Datei oder eines Ordners
+project-missing = Die Datei oder der Ordner existiert nicht
+project-no-recent = Keine zuletzt geöffneten Projekte
+project-pin = Anheften
+project-unpin = Lösen
+project-remove = Aus der Liste entfernen
+project-remove-missing = Fehlende entfernen
+project-layout-failed = Das Layout von { $name } konnte nicht geladen werden
+
+## File drop
+file-drop-here = Zum Öffnen hier ablegen
+file-drop-unsupported = Kein Tab öffnet { $name }
+file-drop-failed = { $name } konnte nicht geöffnet werden: { $error }
+
+## Maximized pane
+pane-maximize = Bereich maximieren
+pane-distraction-free = Ablenkungsfreier Modus
+pane-restore = Kacheln wiederherstellen
+pane-restore-hint = Doppelklicken, um die Kacheln wiederherzustellen
+
+## Inspector
+inspector-kind = Art
+inspector-kind-tabs = Tabs
+inspector-kind-horizontal = Horizontal
+inspector-kind-vertical = Vertikal
+inspector-kind-grid = Raster
+inspector-rename = Umbenennen
+inspector-reset-name = Name zurücksetzen
+inspector-delete = Löschen
+inspector-visible = Sichtbar
+inspector-active = Aktiv
+inspector-share = Anteil
+
+## Layout and outline sections
+layout-active-tiles = Aktive Kacheln
+layout-copy = Layout kopieren
+layout-copy-hint = Das Kachel-Layout als RON kopieren, verwendbar mit --layout
+layout-all-panes-have-tabs = Alle Bereiche brauchen Tabs:
+layout-join-nested = Verschachtelte Container zusammenführen:
+layout-tab-bar-height = Höhe der Tableiste:
+layout-gap-width = Abstand:
+layout-show-view-numbers = Ansichtsnummern zeigen:
+outline-empty = Nichts zu zeigen für die sichtbaren Tabs
+
+## Theme editor
+theme-base = Basis
+theme-color-text = Text
+theme-color-panel = Leiste
+theme-color-window = Fenster
+theme-color-text-field = Textfeld
+theme-color-widget = Steuerelement
+theme-color-stroke = Linie
+theme-color-accent = Akzent
+theme-color-hyperlink = Link
+theme-color-warning = Warnung
+theme-color-error = Fehler
+theme-rounding = Rundung
+theme-stroke-width = Linienbreite
+theme-item-spacing = Elementabstand
+theme-button-padding = Innenabstand von Schaltflächen
+theme-window-margin = Fensterrand
+theme-size-body = Textgröße
+theme-size-heading = Überschriftgröße
+theme-size-monospace = Größe der Festbreitenschrift
+theme-size-small = Größe von kleinem Text
+theme-preview = Vorschau
+theme-preview-body = Fließtext
+theme-preview-button = Schaltfläche
+theme-preview-selected = Ausgewählt
+theme-preview-checkbox = Kontrollkästchen
+theme-preview-monospace = Festbreite
+theme-preview-small = Kleiner Text
+theme-none = Keine Designs
+theme-editing = Zu bearbeitendes Design
+theme-new = Neu
+theme-new-hint = Mit dem aktuellen Stil beginnen
+theme-new-name = Eigenes
+theme-duplicate = Duplizieren
+theme-delete = Löschen
+theme-in-use = In Verwendung
+theme-use = Verwenden
+theme-name = Name
+theme-name-empty = Designs brauchen einen Namen
+theme-name-taken = Ein anderes Design hat diesen Namen
+theme-export = Exportieren
+theme-exported = Design „{ $name }“ { $location }
+theme-export-failed = Export fehlgeschlagen: { $error }
+theme-import-label = Ein zuvor exportiertes Design importieren:
+theme-import-file = Aus Datei…
+theme-import-hint = RON hier einfügen
+theme-import = Importieren
+theme-import-failed = Import fehlgeschlagen: { $error }
+theme-imported = Design „{ $name }“ importiert
+theme-not-a-theme = Kein Design: { $error }
+theme-import-title = Design importieren
+theme-import-path-hint = Pfad einer .ron-Datei
+theme-cancel = Abbrechen
+
+## Fonts
+fonts-zoom = Zoom:
+fonts-zoom-shortcuts = Auch { $in }, { $out } und { $reset }
+fonts-files = Schriftdateien
+fonts-remove-file = Entfernen
+fonts-files-unavailable = Schriftdateien laden ist im Web nicht verfügbar
+fonts-file-name = Name
+fonts-file-path = Pfad einer .ttf- oder .otf-Datei
+fonts-add-file = Hinzufügen
+fonts-add-cjk = CJK-Ersatzschrift hinzufügen
+fonts-add-cjk-hint = { $path } für Chinesisch, Japanisch und Koreanisch verwenden
+fonts-proportional = Proportional
+fonts-monospace = Festbreite
+fonts-add-fallback = Ersatzschrift hinzufügen…
+fonts-move-up = Nach oben
+fonts-move-down = Nach unten
+fonts-remove-font = Aus der Familie entfernen
+fonts-reset = Schriften zurücksetzen
+
+## About window
+about-version = Version:
+about-commit = Commit:
+about-built = Erstellt:
+about-target = Ziel:
+about-features = Features:
+about-renderer = Renderer:
+about-unknown = unbekannt
+about-copy-diagnostics = Diagnose kopieren
+about-copy-diagnostics-hint = Das Obige als Text kopieren, z. B. für Fehlerberichte
+about-powered-by = Basiert auf
+about-and = und
+
+## Debug window
+debug-frame-time = Framezeit
+debug-mean-cpu = Mittlere CPU-Nutzung: { $ms } ms / Frame
+debug-repaint-causes = Gründe für Neuzeichnen
+debug-none = Keine
+debug-tiles = Kacheln:
+debug-panes = Bereiche:
+debug-containers = Container:
+debug-textures = Texturen:
+debug-last-save-size = Größe beim letzten Speichern:
+debug-not-saved = Noch nicht gespeichert
+debug-last-save-error = Letzter Speicherfehler:
+debug-storage = Speicher
+debug-storage-file = Datei: { $path }
+debug-storage-browser = localStorage des Browsers
+debug-storage-refresh = Aktualisieren
+debug-storage-empty = Noch nichts gespeichert
+debug-storage-total = Gesamt
+debug-egui-memory = egui-Speicher
+debug-egui-textures = egui-Texturen
+debug-profiler = Profiler
+
+## Profiler
+profiler-capture = Aufzeichnen
+profiler-pause = Anhalten
+profiler-dump = Frames speichern
+profiler-dump-hint = Alle aufgezeichneten Frames als .puffin-Datei im Arbeitsverzeichnis speichern
+profiler-capture-off = Aufzeichnen aktivieren, um Bereiche zu erfassen
+profiler-no-frames = Noch keine Frames aufgezeichnet
+profiler-frame = Frame #{ $index }: { $ms } ms, { $scopes } Bereiche
+
+## Notifications window
+notifications-dismiss = Schließen
+notifications-log-toasts = Toasts für Log-Meldungen:
+notifications-log-toasts-hint = Log-Meldungen ab dieser Stufe erscheinen auch als Toasts
+notifications-log-toasts-web = Im Web nicht verfügbar
+notifications-off = Aus
+notifications-info = Info
+notifications-success = Erfolg
+notifications-warning = Warnung
+notifications-error = Fehler
+notifications-count = { $count } Benachrichtigungen
+notifications-clear = Leeren
+notifications-none = Noch nichts
+
+## Export
+export-saved = gespeichert unter { $file }
+export-downloaded = heruntergeladen als { $file }
+
+## Storage problems
+storage-title = Speicher
+storage-load-failed = Gespeicherter Zustand konnte nicht geladen werden
+storage-load-failed-default = Stattdessen wird der Standardzustand verwendet: { $error }
+storage-load-failed-kept = Der gespeicherte Zustand bleibt erhalten, bis er verworfen wird.
+storage-save-failed = Zustand konnte nicht gespeichert werden
+storage-quota-exceeded = Änderungen gehen beim Schließen der Seite verloren. Den Zustand exportieren oder einige Tabs schließen.
+storage-size = Größe: { $bytes } Bytes
+storage-exported = Zustand { $location }
+storage-export-failed = Export fehlgeschlagen: { $error }
+storage-export = Zustand exportieren
+storage-copy = Kopieren
+storage-copy-hint = Den Zustand in die Zwischenablage kopieren
+storage-discard = Verwerfen
+storage-discard-hint = Den gespeicherten Zustand beim nächsten Speichern überschreiben
+storage-dismiss = Schließen
+
+## Crash dialog
+crash-title = Die App ist beim letzten Mal abgestürzt
+crash-message = Version { $version }: { $message }
+crash-show-report = Bericht zeigen
+crash-exported = Bericht { $location }
+crash-export-failed = Export fehlgeschlagen: { $error }
+crash-restore = Zustand wiederherstellen
+crash-restore-hint = Mit den Tabs und Fenstern von vor dem Absturz weitermachen
+crash-export = Bericht exportieren
+crash-copy = Bericht kopieren
+crash-dismiss = Schließen
+
+## Update banner
+update-available = Neue Version verfügbar —
+update-reload = Neu laden
+update-later = Später
@@ -0,0 +1,325 @@
+# English, the fallback for keys missing from other languages.
+
+## Menu bar
+menu-file = File
+menu-edit = Edit
+menu-window = Window
+menu-help = Help
+menu-theme = Theme
+menu-quit = Quit
+menu-reset-mem = Reset mem
+side-panel-toggle = Show/Hide side panel
+
+## Side panel
+side-panel-navigator = Navigator
+side-panel-choose-sections = Choose side panel sections
+side-panel-move-up = Move up
+side-panel-move-down = Move down
+side-panel-hide = Hide
+section-outline = Outline
+section-layout = Layout
+
+## Edit menu
+edit-undo = Undo
+edit-undo-action = Undo { $action }
+edit-redo = Redo
+edit-redo-action = Redo { $action }
+edit-group = Group quick edits
+edit-group-hint = Undo edits of the same kind made in quick succession together
+
+## Undo history entries
+action-add-tab = Add tab
+action-close-tab = Close tab
+action-move-tile = Move tile
+action-resize = Resize
+action-show-hide-tile = Show/hide tile
+action-change-container-kind = Change container kind
+action-select-tab = Select tab
+action-rename-tab = Rename tab
+action-remove-tile = Remove tile
+action-edit-text = Edit text
+action-change-value = Change value
+action-open-file = Open file
+
+## Theme menu
+theme-system = System
+theme-light = Light
+theme-dark = Dark
+
+## Confirm exit
+confirm-exit-title = Confirm exit
+confirm-exit-yes = Yes
+confirm-exit-no = No
+
+## Windows
+window-about = About
+window-settings = Settings
+window-settings-hint = Open configuration window
+window-log-viewer = Log viewer
+window-debug = Debug
+window-notifications = Notifications
+window-tasks = Tasks
+
+## Settings
+settings-general = General
+settings-theme = Theme
+settings-fonts = Fonts
+settings-language = Language
+
+## Tabs
+tab-a = Tab A
+tab-b = Tab B
+tab-add = Add tab
+tab-failed = { $title } stopped working
+tab-reset = Reset tab state
+tab-reset-hint = Replace the tab with a new one of the same kind
+tab-close = Close
+tab-copy-report = Copy report
+tab-new = New { $kind }
+tab-log = Log
+tab-a-text = Text:
+tab-a-characters = { $count } characters
+tab-b-value = Value
+tab-b-slider-value = Slider value
+tab-b-notify = Notify
+tab-b-slider-at = Slider is at { $value }
+tab-b-count-primes = Count primes
+tab-b-count-primes-task = Count primes below { $limit }
+tab-b-primes = { $count } primes
+
+## Status bar
+status-no-tab = No tab focused
+status-tasks = Tasks: { $count }
+status-log = { $warnings } warnings, { $errors } errors
+status-log-hint = Warnings and errors logged since you last opened the Log viewer from here
+status-frame-time = { $ms } ms/frame
+
+## Tasks window
+tasks-running = { $count } running
+tasks-clear-finished = Clear finished
+tasks-none = No tasks yet
+tasks-cancel = Cancel
+tasks-cancelled = Cancelled
+tasks-elapsed = { $seconds } s
+
+## Projects
+project-open = Open…
+project-open-file = Open file…
+project-open-folder = Open folder…
+project-open-recent = Open Recent
+project-open-title = Open project
+project-open-button = Open
+project-cancel = Cancel
+project-close = Close project
+project-path-hint = Path of a file or directory
+project-missing = The file or directory doesn't exist
+project-no-recent = No recent projects
+project-pin = Pin
+project-unpin = Unpin
+project-remove = Remove from the list
+project-remove-missing = Remove missing
+project-layout-failed = The layout of { $name } could not be loaded
+
+## File drop
+file-drop-here = Drop to open here
+file-drop-unsupported = No tab opens { $name }
+file-drop-failed = Failed to open { $name }: { $error }
+
+## Maximized pane
+pane-maximize = Maximize pane
+pane-distraction-free = Distraction-free mode
+pane-restore = Restore tiles
+pane-restore-hint = Double-click to restore the tiles
+
+## Inspector
+inspector-kind = Kind
+inspector-kind-tabs = Tabs
+inspector-kind-horizontal = Horizontal
+inspector-kind-vertical = Vertical
+inspector-kind-grid = Grid
+inspector-rename = Rename
+inspector-reset-name = Reset name
+inspector-delete = Delete
+inspector-visible = Visible
+inspector-active = Active
+inspector-share = Share
+
+## Layout and outline sections
+layout-active-tiles = Active tiles
+layout-copy = Copy layout
+layout-copy-hint = Copy the tile layout as RON, usable with --layout
+layout-all-panes-have-tabs = All panes must have tabs:
+layout-join-nested = Join nested containers:
+layout-tab-bar-height = Tab bar height:
+layout-gap-width = Gap width:
+layout-show-view-numbers = Show view numbers:
+outline-empty = Nothing to show for the visible tabs
+
+## Theme editor
+theme-base = Base
+theme-color-text = Text
+theme-color-panel = Panel
+theme-color-window = Window
+theme-color-text-field = Text field
+theme-color-widget = Widget
+theme-color-stroke = Stroke
+theme-color-accent = Accent
+theme-color-hyperlink = Hyperlink
+theme-color-warning = Warning
+theme-color-error = Error
+theme-rounding = Rounding
+theme-stroke-width = Stroke width
+theme-item-spacing = Item spacing
+theme-button-padding = Button padding
+theme-window-margin = Window margin
+theme-size-body = Body size
+theme-size-heading = Heading size
+theme-size-monospace = Monospace size
+theme-size-small = Small size
+theme-preview = Preview
+theme-preview-body = Body text
+theme-preview-button = Button
+theme-preview-selected = Selected
+theme-preview-checkbox = Checkbox
+theme-preview-monospace = monospace
+theme-preview-small = Small text
+theme-none = No themes
+theme-editing = Theme to edit
+theme-new = New
+theme-new-hint = Start from the current style
+theme-new-name = Custom
+theme-duplicate = Duplicate
+theme-delete = Delete
+theme-in-use = In use
+theme-use = Use
+theme-name = Name
+theme-name-empty = Themes need a name
+theme-name-taken = Another theme has this name
+theme-export = Export
+theme-exported = Theme "{ $name }" { $location }
+theme-export-failed = Export failed: { $error }
+theme-import-label = Import a theme exported before:
+theme-import-file = From file…
+theme-import-hint = Paste RON here
+theme-import = Import
+theme-import-failed = Import failed: { $error }
+theme-imported = Imported theme "{ $name }"
+theme-not-a-theme = Not a theme: { $error }
+theme-import-title = Import theme
+theme-import-path-hint = Path of a .ron file
+theme-cancel = Cancel
+
+## Fonts
+fonts-zoom = Zoom:
+fonts-zoom-shortcuts = Also { $in }, { $out } and { $reset }
+fonts-files = Font files
+fonts-remove-file = Remove
+fonts-files-unavailable = Loading font files is not available on the web
+fonts-file-name = Name
+fonts-file-path = Path to a .ttf or .otf file
+fonts-add-file = Add
+fonts-add-cjk = Add CJK fallback
+fonts-add-cjk-hint = Use { $path } for Chinese, Japanese and Korean
+fonts-proportional = Proportional
+fonts-monospace = Monospace
+fonts-add-fallback = Add fallback…
+fonts-move-up = Move up
+fonts-move-down = Move down
+fonts-remove-font = Remove from the family
+fonts-reset = Reset fonts
+
+## About window
+about-version = Version:
+about-commit = Commit:
+about-built = Built:
+about-target = Target:
+about-features = Features:
+about-renderer = Renderer:
+about-unknown = unknown
+about-copy-diagnostics = Copy diagnostics
+about-copy-diagnostics-hint = Copy the above as text, e.g. for bug reports
+about-powered-by = Powered by
+about-and = and
+
+## Debug window
+debug-frame-time = Frame time
+debug-mean-cpu = Mean CPU usage: { $ms } ms / frame
+debug-repaint-causes = Repaint causes
+debug-none = None
+debug-tiles = Tiles:
+debug-panes = Panes:
+debug-containers = Containers:
+debug-textures = Textures:
+debug-last-save-size = Last save size:
+debug-not-saved = Not saved yet
+debug-last-save-error = Last save error:
+debug-storage = Storage
+debug-storage-file = File: { $path }
+debug-storage-browser = Browser localStorage
+debug-storage-refresh = Refresh
+debug-storage-empty = Nothing stored yet
+debug-storage-total = Total
+debug-egui-memory = egui memory
+debug-egui-textures = egui textures
+debug-profiler = Profiler
+
+## Profiler
+profiler-capture = Capture
+profiler-pause = Pause
+profiler-dump = Dump frames
+profiler-dump-hint = Save all captured frames to a .puffin file in the working directory
+profiler-capture-off = Enable capture to record scopes
+profiler-no-frames = No frames captured yet
+profiler-frame = Frame #{ $index }: { $ms } ms, { $scopes } scopes
+
+## Notifications window
+notifications-dismiss = Dismiss
+notifications-log-toasts = Toasts for log messages:
+notifications-log-toasts-hint = Log messages of this level and above also show up as toasts
+notifications-log-toasts-web = Not available on the web
+notifications-off = Off
+notifications-info = Info
+notifications-success = Success
+notifications-warning = Warning
+notifications-error = Error
+notifications-count = { $count } notifications
+notifications-clear = Clear
+notifications-none = Nothing yet
+
+## Export
+export-saved = saved to { $file }
+export-downloaded = downloaded as { $file }
+
+## Storage problems
+storage-title = Storage
+storage-load-failed = Saved state could not be loaded
+storage-load-failed-default = The default state is used instead: { $error }
+storage-load-failed-kept = The saved state is kept until you discard it.
+storage-save-failed = State could not be saved
+storage-quota-exceeded = Changes are lost when the page is closed. Export the state or close some tabs.
+storage-size = Size: { $bytes } bytes
+storage-exported = State { $location }
+storage-export-failed = Export failed: { $error }
+storage-export = Export state
+storage-copy = Copy
+storage-copy-hint = Copy the state to the clipboard
+storage-discard = Discard
+storage-discard-hint = Overwrite the saved state on the next save
+storage-dismiss = Dismiss
+
+## Crash dialog
+crash-title = The app crashed last time
+crash-message = Version { $version }: { $message }
+crash-show-report = Show report
+crash-exported = Report { $location }
+crash-export-failed = Export failed: { $error }
+crash-restore = Restore state
+crash-restore-hint = Continue with the tabs and windows from before the crash
+crash-export = Export report
+crash-copy = Copy report
+crash-dismiss = Dismiss
+
+## Update banner
+update-available = New version available —
+update-reload = Reload
+update-later = Later
@@ -0,0 +1,165 @@
+//! Translations of the UI strings, looked up with [`Context::tr`].
+//!
+//! Every language is a bundle in a subset of the [Fluent](https://projectfluent.org) syntax, next
+//! to this file: `key = text` messages with `{ $name }` placeables, `#` comments and indented
+//! continuation lines. Keys missing from a translation fall back to English.
+
+use crate::prelude::*;
+use std::collections::BTreeMap;
+use strum::EnumIter;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    /// BCP 47 language tag.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+        }
+    }
+
+    /// Name of the language in the language itself, for the language picker.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("en.ftl"),
+            Language::German => include_str!("de.ftl"),
+        }
+    }
+
+    pub fn bundle(self) -> Bundle {
+        Bundle::parse(self.source())
+    }
+}
+
+/// The messages of one language.
+#[derive(Default)]
+pub struct Bundle {
+    messages: BTreeMap<String, String>,
+}
+
+impl Bundle {
+    pub fn parse(source: &str) -> Self {
+        let mut messages = BTreeMap::new();
+        let mut current: Option<String> = None;
+        for line in source.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) {
+                if let Some(value) = current.as_ref().and_then(|key| messages.get_mut(key)) {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(trimmed);
+                }
+                continue;
+            }
+            match line.split_once('=') {
+                Some((key, value)) => {
+                    let key = key.trim().to_owned();
+                    messages.insert(key.clone(), value.trim().to_owned());
+                    current = Some(key);
+                }
+                None => {
+                    warn!("Ignoring translation line without a key: {line:?}");
+                    current = None;
+                }
+            }
+        }
+        Self { messages }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+}
+
+/// Fill in the `{ $name }` placeables of a message, unknown ones are left as they are.
+fn format(pattern: &str, args: &[(&str, &str)]) -> String {
+    let mut text = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        text.push_str(&rest[..start]);
+        let placeable = &rest[start..=start + len];
+        let value = placeable[1..placeable.len() - 1]
+            .trim()
+            .strip_prefix('$')
+            .and_then(|name| args.iter().find(|(arg, _)| *arg == name));
+        match value {
+            Some((_, value)) => text.push_str(value),
+            None => text.push_str(placeable),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    text.push_str(rest);
+    text
+}
+
+pub struct Localizer {
+    language: Language,
+    bundle: Bundle,
+    fallback: Bundle,
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self {
+            language: Language::English,
+            bundle: Bundle::default(),
+            fallback: Language::English.bundle(),
+        }
+    }
+}
+
+impl Localizer {
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        if language == self.language {
+            return;
+        }
+        info!("Switching language to {}", language.code());
+        self.language = language;
+        // English is the fallback, no need to have it twice.
+        self.bundle = match language {
+            Language::English => Bundle::default(),
+            _ => language.bundle(),
+        };
+    }
+
+    /// The message in the current language, English if it isn't translated, or the key itself.
+    pub fn tr(&self, key: &str) -> String {
+        self.tr_args(key, &[])
+    }
+
+    /// Same as [`Localizer::tr`], with values for the `{ $name }` placeables.
+    pub fn tr_args(&self, key: &str, args: &[(&str, &str)]) -> String {
+        match self.bundle.get(key).or_else(|| self.fallback.get(key)) {
+            Some(pattern) => format(pattern, args),
+            None => key.to_owned(),
+        }
+    }
+}
@@ -1,11 +1,35 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod app;
+mod build_info;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cli;
 pub mod context;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod crash;
+mod diagnostics;
+pub mod file_drop;
+mod fonts;
+pub mod i18n;
+pub mod notifications;
 mod prelude;
-mod sidepanel;
+mod profiler;
+pub mod project;
+#[cfg(target_arch = "wasm32")]
+mod pwa;
+mod route;
+pub mod sidepanel;
+pub mod status_bar;
+mod storage;
 mod tab_viewer;
 mod tabs;
+pub mod tasks;
+mod theme;
+pub mod undo;
 mod windows;
 
-pub use app::TemplateApp;
+pub use app::{check_saved_state, LoadError, StartupOptions, TemplateApp};
+pub use route::Route;
+
+/// Name of the app, also decides where eframe persists the state natively.
+pub const APP_NAME: &str = "eframe template";
@@ -4,15 +4,39 @@
 // When compiling natively:
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result {
+    use clap::Parser as _;
     use tracing_subscriber::layer::SubscriberExt;
     use tracing_subscriber::util::SubscriberInitExt;
     use tracing_subscriber::EnvFilter;
+
+    let args = eframe_template::cli::Args::parse();
+
+    let filter = match &args.log_filter {
+        Some(directives) => EnvFilter::new(directives),
+        None => EnvFilter::from_default_env(),
+    };
+    let log_file = args.log_file.as_ref().map(|path| {
+        let file = std::fs::File::create(path).unwrap_or_else(|e| {
+            eprintln!("Failed to create log file {}: {e}", path.display());
+            std::process::exit(2);
+        });
+        tracing_subscriber::fmt::Layer::default()
+            .with_ansi(false)
+            .with_writer(std::sync::Mutex::new(file))
+    });
+    let cx = eframe_template::context::Context::new();
     let collector = egui_tracing::EventCollector::default();
+    let recent_log = eframe_template::crash::RecentLog::default();
     tracing_subscriber::registry()
         .with(collector.clone())
+        .with(recent_log.clone())
+        .with(eframe_template::notifications::ToastLayer::new(&cx))
+        .with(eframe_template::status_bar::LogCountLayer::new(&cx))
         .with(tracing_subscriber::fmt::Layer::default())
-        .with(EnvFilter::from_default_env())
+        .with(log_file)
+        .with(filter)
         .init();
+    eframe_template::crash::install_hook(recent_log);
     // tracing_subscriber::fmt::init();
 
     // let runtime = tokio::runtime::Builder::new_multi_thread()
@@ -23,11 +47,14 @@ fn main() -> eframe::Result {
     // let _guard = runtime.enter();
     // let (shutdown_event_tx, shutdown_event_rx) = tokio::sync::oneshot::channel::<()>();
 
-    let cx = eframe_template::context::Context::new();
-
-    let native_options = eframe::NativeOptions {
+    let mut native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([400.0, 300.0])
+            .with_inner_size(args.window_size)
+            .with_fullscreen(args.fullscreen)
+            .with_title(match &args.workspace {
+                Some(workspace) => format!("eframe template - {workspace}"),
+                None => "eframe template".to_owned(),
+            })
             .with_min_inner_size([300.0, 220.0])
             .with_icon(
                 // NOTE: Adding an icon is optional
@@ -36,12 +63,16 @@ fn main() -> eframe::Result {
             ),
         ..Default::default()
     };
+    if let Some(renderer) = args.renderer {
+        native_options.renderer = renderer.into();
+    }
+    let options = args.startup_options();
     let ui_result = eframe::run_native(
-        "eframe template",
+        eframe_template::APP_NAME,
         native_options,
         Box::new(|cc| {
             Ok(Box::new(eframe_template::TemplateApp::new(
-                cc, cx, collector,
+                cc, cx, options, collector,
                 // shutdown_event_tx,
             )))
         }),
@@ -85,7 +116,13 @@ fn main() {
             .start(
                 canvas,
                 web_options,
-                Box::new(|cc| Ok(Box::new(eframe_template::TemplateApp::new(cc, cx)))),
+                Box::new(|cc| {
+                    Ok(Box::new(eframe_template::TemplateApp::new(
+                        cc,
+                        cx,
+                        Default::default(),
+                    )))
+                }),
             )
             .await;
 
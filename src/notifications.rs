@@ -0,0 +1,354 @@
+//! Toasts raised with [`Context::notify`], and the history shown in the Notifications window.
+
+use crate::prelude::*;
+use egui::{vec2, Align2, Color32, Id, Order, RichText};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use strum::{AsRefStr, EnumIter};
+
+/// How many notifications the history keeps.
+const HISTORY_LEN: usize = 200;
+
+/// How many toasts are shown at once, the newest ones.
+const MAX_TOASTS: usize = 5;
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, EnumIter, AsRefStr,
+)]
+pub enum NotifyLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotifyLevel {
+    fn icon(self) -> &'static str {
+        match self {
+            NotifyLevel::Info => "ℹ",
+            NotifyLevel::Success => "✔",
+            NotifyLevel::Warning => "⚠",
+            NotifyLevel::Error => "🗙",
+        }
+    }
+
+    fn title_key(self) -> &'static str {
+        match self {
+            NotifyLevel::Info => "notifications-info",
+            NotifyLevel::Success => "notifications-success",
+            NotifyLevel::Warning => "notifications-warning",
+            NotifyLevel::Error => "notifications-error",
+        }
+    }
+
+    fn color(self, visuals: &egui::Visuals) -> Color32 {
+        match self {
+            NotifyLevel::Info => visuals.text_color(),
+            NotifyLevel::Success => Color32::from_rgb(0, 160, 60),
+            NotifyLevel::Warning => visuals.warn_fg_color,
+            NotifyLevel::Error => visuals.error_fg_color,
+        }
+    }
+
+    /// How long a toast stays up, in seconds.
+    fn duration(self) -> f64 {
+        match self {
+            NotifyLevel::Info | NotifyLevel::Success => 4.0,
+            NotifyLevel::Warning => 8.0,
+            NotifyLevel::Error => 12.0,
+        }
+    }
+}
+
+/// Button on a toast, clicking it runs the callback and dismisses the toast.
+pub struct NotificationAction {
+    label: String,
+    on_click: Option<Box<dyn FnOnce(&Context) + Send>>,
+}
+
+impl NotificationAction {
+    pub fn new(label: impl Into<String>, on_click: impl FnOnce(&Context) + Send + 'static) -> Self {
+        Self {
+            label: label.into(),
+            on_click: Some(Box::new(on_click)),
+        }
+    }
+}
+
+pub struct Notification {
+    pub level: NotifyLevel,
+    pub text: String,
+    actions: Vec<NotificationAction>,
+    /// `egui` time the toast was first shown, or last hovered.
+    shown_at: Option<f64>,
+    /// Closed by the user or timed out, still in the history.
+    dismissed: bool,
+}
+
+#[derive(Default)]
+pub struct Notifications {
+    /// Oldest first.
+    history: VecDeque<Notification>,
+    log_sink: Arc<std::sync::Mutex<LogSink>>,
+    /// To repaint when notified from another thread.
+    egui_ctx: Option<egui::Context>,
+}
+
+/// Log messages waiting to become toasts.
+///
+/// Separate from [`Notifications`] and never waited for by the log layer, as egui itself logs
+/// while the notifications are locked to show the toasts.
+#[derive(Default)]
+struct LogSink {
+    level: Option<NotifyLevel>,
+    pending: Vec<(NotifyLevel, String)>,
+}
+
+impl Notifications {
+    pub fn push(&mut self, level: NotifyLevel, text: String, actions: Vec<NotificationAction>) {
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(Notification {
+            level,
+            text,
+            actions,
+            shown_at: None,
+            dismissed: false,
+        });
+        if let Some(ctx) = &self.egui_ctx {
+            ctx.request_repaint();
+        }
+    }
+
+    /// Oldest first.
+    pub fn history(&self) -> impl DoubleEndedIterator<Item = &Notification> {
+        self.history.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Log messages at or above this level also raise toasts. Native only.
+    pub fn set_log_level(&mut self, level: Option<NotifyLevel>) {
+        if let Ok(mut sink) = self.log_sink.lock() {
+            sink.level = level;
+        }
+    }
+
+    pub(crate) fn set_egui_ctx(&mut self, ctx: egui::Context) {
+        self.egui_ctx = Some(ctx);
+    }
+
+    /// Turn log messages received since the last frame into notifications.
+    fn drain_log(&mut self) {
+        let pending = match self.log_sink.lock() {
+            Ok(mut sink) => std::mem::take(&mut sink.pending),
+            Err(_) => return,
+        };
+        for (level, text) in pending {
+            self.push(level, text, Vec::new());
+        }
+    }
+}
+
+/// Show the pending toasts stacked in the bottom right corner.
+pub(crate) fn show_toasts(ctx: &egui::Context, cx: &Context) {
+    let now = ctx.input(|i| i.time);
+    let mut clicked = Vec::new();
+    {
+        let mut notifications = cx.notifications();
+        notifications.drain_log();
+        let mut next_timeout = f64::INFINITY;
+        let active: Vec<&mut Notification> = notifications
+            .history
+            .iter_mut()
+            .filter(|notification| !notification.dismissed)
+            .collect();
+        let skip = active.len().saturating_sub(MAX_TOASTS);
+        egui::Area::new(Id::new("toasts"))
+            .anchor(Align2::RIGHT_BOTTOM, vec2(-8.0, -8.0))
+            .order(Order::Foreground)
+            .show(ctx, |ui| {
+                for notification in active.into_iter().skip(skip) {
+                    let shown_at = *notification.shown_at.get_or_insert(now);
+                    let remaining = notification.level.duration() - (now - shown_at);
+                    if remaining <= 0.0 {
+                        notification.dismissed = true;
+                        continue;
+                    }
+                    next_timeout = next_timeout.min(remaining);
+
+                    let response = egui::Frame::popup(ui.style())
+                        .show(ui, |ui| {
+                            ui.set_max_width(320.0);
+                            ui.horizontal(|ui| {
+                                let color = notification.level.color(ui.visuals());
+                                ui.label(RichText::new(notification.level.icon()).color(color));
+                                ui.label(&notification.text);
+                                if ui
+                                    .small_button("✖")
+                                    .on_hover_text(cx.tr("notifications-dismiss"))
+                                    .clicked()
+                                {
+                                    notification.dismissed = true;
+                                }
+                            });
+                            if !notification.actions.is_empty() {
+                                ui.horizontal(|ui| {
+                                    for action in &mut notification.actions {
+                                        if ui.button(&action.label).clicked() {
+                                            clicked.extend(action.on_click.take());
+                                            notification.dismissed = true;
+                                        }
+                                    }
+                                });
+                            }
+                        })
+                        .response;
+                    // Keep the toast up while the user reads it.
+                    if response.contains_pointer() {
+                        notification.shown_at = Some(now);
+                    }
+                }
+            });
+        if next_timeout.is_finite() {
+            ctx.request_repaint_after_secs(next_timeout as f32);
+        }
+    }
+    // Outside the lock, callbacks may notify again.
+    for on_click in clicked {
+        on_click(cx);
+    }
+}
+
+/// The history of notifications, and whether log messages raise toasts.
+#[derive(Default, Serialize, Deserialize)]
+pub struct NotificationsWindow {
+    log_level: Option<NotifyLevel>,
+}
+
+impl NotificationsWindow {
+    /// Apply the persisted settings.
+    pub fn attach(&self, cx: &Context) {
+        cx.notifications().set_log_level(self.log_level);
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        ui.horizontal(|ui| {
+            let label = ui.label(cx.tr("notifications-log-toasts"));
+            let selected = self
+                .log_level
+                .map_or("notifications-off", NotifyLevel::title_key);
+            let before = self.log_level;
+            egui::ComboBox::from_id_salt("notify_log_level")
+                .selected_text(cx.tr(selected))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.log_level, None, cx.tr("notifications-off"));
+                    for level in [NotifyLevel::Info, NotifyLevel::Warning, NotifyLevel::Error] {
+                        ui.selectable_value(
+                            &mut self.log_level,
+                            Some(level),
+                            cx.tr(level.title_key()),
+                        );
+                    }
+                })
+                .response
+                .labelled_by(label.id)
+                .on_hover_text(cx.tr("notifications-log-toasts-hint"));
+            if self.log_level != before {
+                self.attach(cx);
+            }
+        });
+        if cfg!(target_arch = "wasm32") {
+            ui.weak(cx.tr("notifications-log-toasts-web"));
+        }
+
+        let mut notifications = cx.notifications();
+        ui.horizontal(|ui| {
+            ui.label(cx.tr_args(
+                "notifications-count",
+                &[("count", &notifications.history.len().to_string())],
+            ));
+            if ui.button(cx.tr("notifications-clear")).clicked() {
+                notifications.clear();
+            }
+        });
+        ui.separator();
+
+        if notifications.history.is_empty() {
+            ui.weak(cx.tr("notifications-none"));
+        }
+        for notification in notifications.history().rev() {
+            ui.horizontal(|ui| {
+                let color = notification.level.color(ui.visuals());
+                ui.label(RichText::new(notification.level.icon()).color(color));
+                ui.label(&notification.text);
+            });
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use log_layer::ToastLayer;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod log_layer {
+    use super::{LogSink, NotifyLevel};
+    use crate::context::Context;
+    use std::fmt::Write as _;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+
+    /// `tracing` layer raising toasts for log messages, see
+    /// [`super::Notifications::set_log_level`].
+    ///
+    /// The toasts show up with the next frame, the layer doesn't request one.
+    pub struct ToastLayer {
+        sink: Arc<Mutex<LogSink>>,
+    }
+
+    impl ToastLayer {
+        pub fn new(cx: &Context) -> Self {
+            Self {
+                sink: cx.notifications().log_sink.clone(),
+            }
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for ToastLayer {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let level = match *event.metadata().level() {
+                tracing::Level::ERROR => NotifyLevel::Error,
+                tracing::Level::WARN => NotifyLevel::Warning,
+                tracing::Level::INFO => NotifyLevel::Info,
+                _ => return,
+            };
+            // Drop the message rather than wait, it may be logged while the sink is drained.
+            let Ok(mut sink) = self.sink.try_lock() else {
+                return;
+            };
+            if sink.level.is_none_or(|min| level < min) {
+                return;
+            }
+            let mut message = MessageVisitor(String::new());
+            event.record(&mut message);
+            sink.pending.push((level, message.0));
+        }
+    }
+
+    struct MessageVisitor(String);
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.0, "{value:?}");
+            }
+        }
+    }
+}
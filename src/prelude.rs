@@ -1,5 +1,10 @@
 pub use crate::context::Context;
+pub use crate::i18n::Language;
+pub use crate::notifications::{NotificationAction, NotifyLevel};
+pub(crate) use crate::profiler::{profile_function, profile_scope};
+pub use crate::status_bar::StatusItem;
 pub use crate::tab_viewer::TabUi;
+pub use crate::tasks::TaskHandle;
 pub use egui::{Ui, WidgetText};
 pub use serde::{Deserialize, Serialize};
 
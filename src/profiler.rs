@@ -0,0 +1,227 @@
+//! Optional [puffin](https://github.com/EmbarkStudios/puffin) instrumentation.
+//!
+//! The `profile_function!` and `profile_scope!` macros compile to nothing unless the `profiling`
+//! cargo feature is enabled, so they can be sprinkled around freely.
+
+#[cfg(feature = "profiling")]
+macro_rules! profile_function {
+    ($($arg:tt)*) => {
+        puffin::profile_function!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "profiling"))]
+macro_rules! profile_function {
+    ($($arg:tt)*) => {};
+}
+
+#[cfg(feature = "profiling")]
+macro_rules! profile_scope {
+    ($($arg:tt)*) => {
+        puffin::profile_scope!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "profiling"))]
+macro_rules! profile_scope {
+    ($($arg:tt)*) => {};
+}
+
+pub(crate) use profile_function;
+pub(crate) use profile_scope;
+
+/// Mark the start of a new frame, call once at the beginning of `App::update`.
+pub(crate) fn new_frame() {
+    #[cfg(feature = "profiling")]
+    puffin::GlobalProfiler::lock().new_frame();
+}
+
+#[cfg(feature = "profiling")]
+pub use viewer::ProfilerViewer;
+
+#[cfg(feature = "profiling")]
+mod viewer {
+    use crate::prelude::*;
+    use egui::{pos2, vec2, Align2, Color32, Rect, Sense, Stroke, TextStyle};
+    use puffin::{FrameData, GlobalFrameView, Reader, ScopeCollection, Stream};
+    use std::sync::Arc;
+
+    const ROW_HEIGHT: f32 = 16.0;
+
+    /// Flamegraph of the latest (or a paused) captured frame.
+    #[derive(Default)]
+    pub struct ProfilerViewer {
+        frame_view: GlobalFrameView,
+        paused: Option<Arc<FrameData>>,
+    }
+
+    impl ProfilerViewer {
+        pub fn ui(&mut self, ui: &mut Ui, cx: &Context) {
+            ui.horizontal(|ui| {
+                let mut scopes_on = puffin::are_scopes_on();
+                if ui
+                    .checkbox(&mut scopes_on, cx.tr("profiler-capture"))
+                    .changed()
+                {
+                    puffin::set_scopes_on(scopes_on);
+                }
+
+                let mut is_paused = self.paused.is_some();
+                if ui
+                    .toggle_value(&mut is_paused, cx.tr("profiler-pause"))
+                    .changed()
+                {
+                    self.paused = if is_paused {
+                        self.frame_view.lock().latest_frame()
+                    } else {
+                        None
+                    };
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui
+                    .button(cx.tr("profiler-dump"))
+                    .on_hover_text(cx.tr("profiler-dump-hint"))
+                    .clicked()
+                {
+                    self.dump_frames();
+                }
+            });
+
+            if !puffin::are_scopes_on() {
+                ui.weak(cx.tr("profiler-capture-off"));
+                return;
+            }
+
+            let frame_view = self.frame_view.lock();
+            let frame = self.paused.clone().or_else(|| frame_view.latest_frame());
+            let Some(frame) = frame else {
+                ui.weak(cx.tr("profiler-no-frames"));
+                return;
+            };
+            match frame.unpacked() {
+                Ok(frame) => {
+                    ui.label(cx.tr_args(
+                        "profiler-frame",
+                        &[
+                            ("index", &frame.frame_index().to_string()),
+                            ("ms", &format!("{:.2}", frame.duration_ns() as f64 * 1e-6)),
+                            ("scopes", &frame.meta.num_scopes.to_string()),
+                        ],
+                    ));
+                    let range_ns = frame.range_ns();
+                    for (thread, stream_info) in &frame.thread_streams {
+                        ui.label(&thread.name);
+                        let rows = stream_info.depth.max(1) as f32;
+                        let size = vec2(ui.available_width().max(200.0), rows * ROW_HEIGHT);
+                        let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+                        let flamegraph = Flamegraph {
+                            ui,
+                            rect,
+                            range_ns,
+                            scopes: frame_view.scope_collection(),
+                            stream: &stream_info.stream,
+                        };
+                        flamegraph.paint(Reader::from_start(&stream_info.stream), 0);
+                    }
+                }
+                Err(e) => {
+                    ui.colored_label(ui.visuals().error_fg_color, format!("{e}"));
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        fn dump_frames(&self) {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            let path = format!("eframe_template_{secs}.puffin");
+            let result = std::fs::File::create(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|mut file| {
+                    self.frame_view
+                        .lock()
+                        .write(&mut file)
+                        .map_err(|e| e.to_string())
+                });
+            match result {
+                Ok(()) => info!("Profiler frames saved to {path}"),
+                Err(e) => error!("Failed to save profiler frames to {path}: {e}"),
+            }
+        }
+    }
+
+    struct Flamegraph<'a> {
+        ui: &'a Ui,
+        rect: Rect,
+        range_ns: (puffin::NanoSecond, puffin::NanoSecond),
+        scopes: &'a ScopeCollection,
+        stream: &'a Stream,
+    }
+
+    impl Flamegraph<'_> {
+        fn paint(&self, reader: Reader<'_>, depth: usize) {
+            let (min_ns, max_ns) = self.range_ns;
+            let duration_ns = (max_ns - min_ns).max(1) as f32;
+            let to_x = |ns: puffin::NanoSecond| {
+                self.rect.left() + self.rect.width() * (ns - min_ns) as f32 / duration_ns
+            };
+            let painter = self.ui.painter_at(self.rect);
+            let font_id = TextStyle::Small.resolve(self.ui.style());
+
+            for scope in reader.flatten() {
+                let top = self.rect.top() + depth as f32 * ROW_HEIGHT;
+                let scope_rect = Rect::from_min_max(
+                    pos2(to_x(scope.record.start_ns), top),
+                    pos2(to_x(scope.record.stop_ns()), top + ROW_HEIGHT - 1.0),
+                );
+                let name = self
+                    .scopes
+                    .fetch_by_id(&scope.id)
+                    .map(|details| details.name().to_string())
+                    .unwrap_or_else(|| "?".to_owned());
+
+                let color = Color32::from_rgb(200, (120 + 40 * (depth % 4)) as u8, 60);
+                painter.rect_filled(scope_rect, 2.0, color);
+                painter.rect_stroke(
+                    scope_rect,
+                    2.0,
+                    Stroke::new(1.0, Color32::BLACK),
+                    egui::StrokeKind::Inside,
+                );
+                if scope_rect.width() > 30.0 {
+                    painter.with_clip_rect(scope_rect).text(
+                        scope_rect.left_center() + vec2(2.0, 0.0),
+                        Align2::LEFT_CENTER,
+                        &name,
+                        font_id.clone(),
+                        Color32::BLACK,
+                    );
+                }
+
+                let hovered = self.ui.rect_contains_pointer(scope_rect);
+                if hovered {
+                    let text = format!(
+                        "{name} {}\n{:.3} ms",
+                        scope.record.data,
+                        scope.record.duration_ns as f64 * 1e-6
+                    );
+                    egui::show_tooltip_at_pointer(
+                        self.ui.ctx(),
+                        self.ui.layer_id(),
+                        egui::Id::new("flamegraph_tooltip"),
+                        |ui| {
+                            ui.label(text);
+                        },
+                    );
+                }
+
+                if let Ok(children) = Reader::with_offset(self.stream, scope.child_begin_position) {
+                    self.paint(children, depth + 1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! Projects: the file or directory the app works on, opened from the File menu.
+//!
+//! Every project has its own tile layout. Natively it is stored next to the project, in
+//! `<dir>/.eframe_template/layout.ron` for a directory and `<file>.layout.ron` for a file. The
+//! browser only tells the name of what was picked, so on the web the layout goes to local storage.
+
+use crate::prelude::*;
+use egui::{Button, RichText, WidgetInfo, WidgetType};
+use std::path::PathBuf;
+
+/// How many projects Open Recent keeps besides the pinned ones.
+const MAX_RECENT: usize = 10;
+
+/// Directory the app keeps its files in, inside directory projects.
+pub const PROJECT_DIR: &str = ".eframe_template";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Project {
+    /// Only the name on the web.
+    pub path: PathBuf,
+}
+
+impl Project {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn name(&self) -> String {
+        match self.path.file_name() {
+            Some(name) => name.to_string_lossy().into_owned(),
+            None => self.path.display().to_string(),
+        }
+    }
+
+    /// Whether the file or directory is still there, always `true` on the web.
+    pub fn exists(&self) -> bool {
+        cfg!(target_arch = "wasm32") || self.path.exists()
+    }
+
+    /// Where the layout is stored natively.
+    pub fn layout_path(&self) -> PathBuf {
+        if self.path.is_dir() {
+            self.path.join(PROJECT_DIR).join("layout.ron")
+        } else {
+            let mut path = self.path.clone().into_os_string();
+            path.push(".layout.ron");
+            path.into()
+        }
+    }
+
+    /// The stored layout, `None` if the project doesn't have one yet.
+    pub fn load_layout(&self) -> Result<Option<String>, String> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::storage::get_item(&self.storage_key())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let path = self.layout_path();
+            match std::fs::read_to_string(&path) {
+                Ok(layout) => Ok(Some(layout)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(format!("failed to read {}: {e}", path.display())),
+            }
+        }
+    }
+
+    pub fn save_layout(&self, layout: &str) -> Result<(), String> {
+        if !self.exists() {
+            return Err(format!("{} is gone", self.path.display()));
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::storage::set_item(&self.storage_key(), layout).map_err(|e| e.to_string())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let path = self.layout_path();
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+            }
+            std::fs::write(&path, layout)
+                .map_err(|e| format!("failed to write {}: {e}", path.display()))
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn storage_key(&self) -> String {
+        format!("{}/project/{}", eframe::APP_KEY, self.path.display())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecentProject {
+    pub project: Project,
+    /// Pinned projects stay at the top and are never forgotten.
+    pub pinned: bool,
+}
+
+enum RecentAction {
+    Pin(Project, bool),
+    Remove(Project),
+    RemoveMissing,
+}
+
+/// The projects of File → Open Recent, persisted for all workspaces.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecentProjects {
+    /// Most recent first.
+    entries: Vec<RecentProject>,
+}
+
+impl RecentProjects {
+    /// Move the project to the top, forgetting the oldest ones beyond [`MAX_RECENT`].
+    pub fn add(&mut self, project: Project) {
+        let pinned = self
+            .entries
+            .iter()
+            .any(|entry| entry.project == project && entry.pinned);
+        self.entries.retain(|entry| entry.project != project);
+        self.entries.insert(0, RecentProject { project, pinned });
+        let mut unpinned = 0;
+        self.entries.retain(|entry| {
+            if !entry.pinned {
+                unpinned += 1;
+            }
+            entry.pinned || unpinned <= MAX_RECENT
+        });
+    }
+
+    pub fn remove(&mut self, project: &Project) {
+        self.entries.retain(|entry| entry.project != *project);
+    }
+
+    pub fn set_pinned(&mut self, project: &Project, pinned: bool) {
+        for entry in &mut self.entries {
+            if entry.project == *project {
+                entry.pinned = pinned;
+            }
+        }
+    }
+
+    /// Forget the projects whose file or directory is gone, returns how many.
+    pub fn remove_missing(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.project.exists());
+        before - self.entries.len()
+    }
+
+    /// Pinned projects first, then the most recent.
+    pub fn iter(&self) -> impl Iterator<Item = &RecentProject> {
+        let pinned = self.entries.iter().filter(|entry| entry.pinned);
+        pinned.chain(self.entries.iter().filter(|entry| !entry.pinned))
+    }
+
+    /// Content of the Open Recent menu, returns the project to open.
+    pub(crate) fn menu_ui(&mut self, ui: &mut Ui, cx: &Context) -> Option<Project> {
+        let mut open = None;
+        let mut action = None;
+        if self.entries.is_empty() {
+            ui.weak(cx.tr("project-no-recent"));
+        }
+        let mut any_missing = false;
+        for entry in self.iter() {
+            let exists = entry.project.exists();
+            any_missing |= !exists;
+            ui.horizontal(|ui| {
+                let pin_name = cx.tr(if entry.pinned {
+                    "project-unpin"
+                } else {
+                    "project-pin"
+                });
+                let pin = ui
+                    .selectable_label(entry.pinned, "📌")
+                    .on_hover_text(&pin_name);
+                pin.widget_info(|| {
+                    WidgetInfo::selected(WidgetType::SelectableLabel, true, entry.pinned, &pin_name)
+                });
+                if pin.clicked() {
+                    action = Some(RecentAction::Pin(entry.project.clone(), !entry.pinned));
+                }
+
+                let name = match exists {
+                    true => RichText::new(entry.project.name()),
+                    false => RichText::new(entry.project.name()).strikethrough(),
+                };
+                let button = ui
+                    .add_enabled(exists, Button::new(name))
+                    .on_hover_text(entry.project.path.display().to_string())
+                    .on_disabled_hover_text(cx.tr("project-missing"));
+                if button.clicked() {
+                    open = Some(entry.project.clone());
+                    ui.close_menu();
+                }
+
+                let remove_name = cx.tr("project-remove");
+                let remove = ui.small_button("✖").on_hover_text(&remove_name);
+                remove.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, &remove_name));
+                if remove.clicked() {
+                    action = Some(RecentAction::Remove(entry.project.clone()));
+                }
+            });
+        }
+        ui.separator();
+        if ui
+            .add_enabled(any_missing, Button::new(cx.tr("project-remove-missing")))
+            .clicked()
+        {
+            action = Some(RecentAction::RemoveMissing);
+        }
+
+        match action {
+            Some(RecentAction::Pin(project, pinned)) => self.set_pinned(&project, pinned),
+            Some(RecentAction::Remove(project)) => self.remove(&project),
+            Some(RecentAction::RemoveMissing) => {
+                let removed = self.remove_missing();
+                info!("Removed {removed} missing projects from the recent ones");
+            }
+            None => {}
+        }
+        open
+    }
+}
+
+/// File → Open: a dialog to type the path natively, the browser's file picker on the web.
+#[derive(Default)]
+pub(crate) struct ProjectPicker {
+    /// Content of the path field while the dialog is open.
+    #[cfg(not(target_arch = "wasm32"))]
+    path: Option<String>,
+    /// Set by the file picker once the user picked something.
+    #[cfg(target_arch = "wasm32")]
+    picked: std::sync::Arc<egui::mutex::Mutex<Option<Project>>>,
+}
+
+impl ProjectPicker {
+    /// The Open entries of the File menu.
+    pub(crate) fn menu_ui(&mut self, ui: &mut Ui, cx: &Context) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if ui.button(cx.tr("project-open")).clicked() {
+            self.path = Some(String::new());
+            ui.close_menu();
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            if ui.button(cx.tr("project-open-file")).clicked() {
+                web::pick(false, self.picked.clone(), ui.ctx().clone());
+                ui.close_menu();
+            }
+            if web::has_directory_picker() && ui.button(cx.tr("project-open-folder")).clicked() {
+                web::pick(true, self.picked.clone(), ui.ctx().clone());
+                ui.close_menu();
+            }
+        }
+    }
+
+    /// Show the dialog while it is open, returns the project once one was picked.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn show(&mut self, ctx: &egui::Context, cx: &Context) -> Option<Project> {
+        let path = self.path.as_mut()?;
+        let mut picked = None;
+        let mut cancel = false;
+        let modal = egui::Modal::new(egui::Id::new("open_project")).show(ctx, |ui| {
+            ui.set_width(320.0);
+            ui.heading(cx.tr("project-open-title"));
+            let response = ui.add(
+                egui::TextEdit::singleline(path)
+                    .hint_text(cx.tr("project-path-hint"))
+                    .desired_width(f32::INFINITY),
+            );
+            let project = Project::new(path.trim());
+            let valid = !path.trim().is_empty() && project.exists();
+            if !path.trim().is_empty() && !valid {
+                ui.colored_label(ui.visuals().error_fg_color, cx.tr("project-missing"));
+            }
+            let entered = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+            ui.add_space(8.0);
+            egui::Sides::new().show(
+                ui,
+                |_ui| {},
+                |ui| {
+                    let open = ui.add_enabled(valid, Button::new(cx.tr("project-open-button")));
+                    if open.clicked() || (valid && entered) {
+                        // The same project typed as `./x` and `/home/me/x` is one recent entry.
+                        let path = std::fs::canonicalize(&project.path).unwrap_or(project.path);
+                        picked = Some(Project::new(path));
+                    }
+                    if ui.button(cx.tr("project-cancel")).clicked() {
+                        cancel = true;
+                    }
+                },
+            );
+        });
+        if picked.is_some() || cancel || modal.should_close() {
+            self.path = None;
+        }
+        picked
+    }
+
+    /// Returns the project once the browser's file picker picked one.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn show(&mut self, _ctx: &egui::Context, _cx: &Context) -> Option<Project> {
+        self.picked.lock().take()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::Project;
+    use crate::prelude::*;
+    use eframe::wasm_bindgen::closure::Closure;
+    use eframe::wasm_bindgen::{JsCast as _, JsValue};
+    use egui::mutex::Mutex;
+    use std::sync::Arc;
+
+    fn window_has(name: &str) -> bool {
+        web_sys::window().is_some_and(|window| {
+            js_sys::Reflect::has(&window, &JsValue::from_str(name)).unwrap_or(false)
+        })
+    }
+
+    /// Only Chromium based browsers can pick directories, with the File System Access API.
+    pub(super) fn has_directory_picker() -> bool {
+        window_has("showDirectoryPicker")
+    }
+
+    /// Let the user pick a file or directory, it ends up in `picked`.
+    pub(super) fn pick(directory: bool, picked: Arc<Mutex<Option<Project>>>, ctx: egui::Context) {
+        let picker = if directory {
+            "showDirectoryPicker"
+        } else {
+            "showOpenFilePicker"
+        };
+        if !window_has(picker) {
+            if let Err(e) = pick_with_input(picked, ctx) {
+                error!("Failed to show the file picker: {e:?}");
+            }
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match show_picker(picker).await {
+                Ok(name) => {
+                    *picked.lock() = Some(Project::new(name));
+                    ctx.request_repaint();
+                }
+                // Also when the user cancels.
+                Err(e) => debug!("Nothing picked: {e:?}"),
+            }
+        });
+    }
+
+    /// Name of the file or directory picked with the File System Access API.
+    async fn show_picker(picker: &str) -> Result<String, JsValue> {
+        let window: JsValue = web_sys::window()
+            .ok_or_else(|| JsValue::from_str("no window"))?
+            .into();
+        let function: js_sys::Function =
+            js_sys::Reflect::get(&window, &JsValue::from_str(picker))?.dyn_into()?;
+        let promise: js_sys::Promise = function.call0(&window)?.dyn_into()?;
+        let result = wasm_bindgen_futures::JsFuture::from(promise).await?;
+        // `showOpenFilePicker` resolves to a list of handles.
+        let handle = if js_sys::Array::is_array(&result) {
+            js_sys::Array::from(&result).get(0)
+        } else {
+            result
+        };
+        js_sys::Reflect::get(&handle, &JsValue::from_str("name"))?
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("the picked handle has no name"))
+    }
+
+    /// Firefox and Safari only pick files, through an upload button.
+    fn pick_with_input(
+        picked: Arc<Mutex<Option<Project>>>,
+        ctx: egui::Context,
+    ) -> Result<(), JsValue> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or_else(|| JsValue::from_str("no document"))?;
+        let input: web_sys::HtmlInputElement = document.create_element("input")?.dyn_into()?;
+        input.set_type("file");
+        let target = input.clone();
+        let on_change = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            if let Some(file) = target.files().and_then(|files| files.get(0)) {
+                *picked.lock() = Some(Project::new(file.name()));
+                ctx.request_repaint();
+            }
+        });
+        input.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+        // There is no telling whether the user cancels, so it lives as long as the page.
+        on_change.forget();
+        input.click();
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Notices new builds installed by the service worker in `assets/sw.js`.
+//!
+//! `index.html` sets `window.swUpdateWaiting` and dispatches `sw-update-waiting` when a new build
+//! is waiting, and provides `window.applySwUpdate` to switch to it.
+
+use crate::prelude::*;
+use eframe::wasm_bindgen::closure::Closure;
+use eframe::wasm_bindgen::{JsCast as _, JsValue};
+use egui::TopBottomPanel;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+pub(crate) struct UpdateBanner {
+    waiting: Arc<AtomicBool>,
+    dismissed: bool,
+}
+
+impl UpdateBanner {
+    pub(crate) fn new(ctx: &egui::Context) -> Self {
+        let waiting = Arc::new(AtomicBool::new(false));
+        if let Some(window) = web_sys::window() {
+            // The event may have fired before the app started.
+            let already_waiting = js_sys::Reflect::get(&window, &"swUpdateWaiting".into())
+                .is_ok_and(|value| value.is_truthy());
+            waiting.store(already_waiting, Ordering::Relaxed);
+
+            let on_waiting = {
+                let waiting = waiting.clone();
+                let ctx = ctx.clone();
+                Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+                    waiting.store(true, Ordering::Relaxed);
+                    ctx.request_repaint();
+                })
+            };
+            match window.add_event_listener_with_callback(
+                "sw-update-waiting",
+                on_waiting.as_ref().unchecked_ref(),
+            ) {
+                // The listener lives as long as the page.
+                Ok(()) => on_waiting.forget(),
+                Err(e) => warn!("Failed to listen for service worker updates: {e:?}"),
+            }
+        }
+        Self {
+            waiting,
+            dismissed: false,
+        }
+    }
+
+    /// Show the banner above everything else while a new build is waiting.
+    pub(crate) fn show(&mut self, ctx: &egui::Context, cx: &Context) {
+        if self.dismissed || !self.waiting.load(Ordering::Relaxed) {
+            return;
+        }
+        TopBottomPanel::top("update_banner").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(cx.tr("update-available"));
+                if ui.button(cx.tr("update-reload")).clicked() {
+                    apply_update();
+                }
+                if ui.button(cx.tr("update-later")).clicked() {
+                    self.dismissed = true;
+                }
+            });
+        });
+    }
+}
+
+fn apply_update() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let result = js_sys::Reflect::get(&window, &"applySwUpdate".into())
+        .and_then(|function| {
+            function
+                .dyn_into::<js_sys::Function>()
+                .map_err(JsValue::from)
+        })
+        .and_then(|function| function.call0(&JsValue::NULL));
+    if let Err(e) = result {
+        warn!("Failed to apply the update, reloading instead: {e:?}");
+        let _ = window.location().reload();
+    }
+}
@@ -0,0 +1,234 @@
+//! Deep links: the URL hash of the web build encodes the workspace and the focused tab,
+//! e.g. `#workspace=review&tab=TabB&nr=2`.
+
+use crate::prelude::*;
+use crate::tabs::{Tab, TabKindDiscriminants, TreeBehavior};
+use egui_tiles::{Tile, Tree};
+
+/// A view of the app that can be shared as a link.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Route {
+    /// Name of the persisted workspace, see [`crate::StartupOptions::workspace`].
+    pub workspace: Option<String>,
+    /// Kind of the focused tab, e.g. `TabB`.
+    pub tab: Option<String>,
+    /// Number of the focused tab, picks the first tab of the kind when missing.
+    pub nr: Option<usize>,
+}
+
+impl Route {
+    /// Parse a URL hash or query string, with or without the leading `#` or `?`.
+    ///
+    /// Unknown keys and malformed values are ignored, so stale links still open the app.
+    pub fn parse(s: &str) -> Self {
+        let mut route = Self::default();
+        let s = s.trim_start_matches(['#', '?']);
+        for pair in s.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value);
+            match key {
+                "workspace" if !value.is_empty() => route.workspace = Some(value),
+                "tab" if !value.is_empty() => route.tab = Some(value),
+                "nr" => route.nr = value.parse().ok(),
+                _ => {}
+            }
+        }
+        route
+    }
+
+    /// Format as a URL hash, including the leading `#`.
+    pub fn to_hash(&self) -> String {
+        let mut pairs = Vec::new();
+        if let Some(workspace) = &self.workspace {
+            pairs.push(format!("workspace={}", percent_encode(workspace)));
+        }
+        if let Some(tab) = &self.tab {
+            pairs.push(format!("tab={}", percent_encode(tab)));
+        }
+        if let Some(nr) = self.nr {
+            pairs.push(format!("nr={nr}"));
+        }
+        format!("#{}", pairs.join("&"))
+    }
+
+    /// Route of the page, the hash wins over the query string.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn from_location(location: &eframe::Location) -> Self {
+        let mut route = Self::parse(&location.hash);
+        if route.workspace.is_none() {
+            route.workspace = location
+                .query_map
+                .get("workspace")
+                .and_then(|values| values.first())
+                .filter(|workspace| !workspace.is_empty())
+                .cloned();
+        }
+        route
+    }
+
+    /// Route of the tab last focused by the user, without workspace.
+    pub(crate) fn focused(tree: &Tree<Tab>, behavior: &TreeBehavior) -> Self {
+        match behavior.focused.and_then(|id| tree.tiles.get(id)) {
+            Some(Tile::Pane(tab)) => Self {
+                workspace: None,
+                tab: Some(TabKindDiscriminants::from(&tab.kind).as_ref().to_owned()),
+                nr: Some(tab.nr),
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Make the tab of this route visible and focused. Returns `false` if there is no such tab.
+    pub(crate) fn apply(&self, tree: &mut Tree<Tab>, behavior: &mut TreeBehavior) -> bool {
+        let Some(kind) = &self.tab else {
+            return false;
+        };
+        let found = tree.tiles.iter().find_map(|(id, tile)| match tile {
+            Tile::Pane(tab)
+                if TabKindDiscriminants::from(&tab.kind).as_ref() == kind
+                    && self.nr.is_none_or(|nr| nr == tab.nr) =>
+            {
+                Some(*id)
+            }
+            _ => None,
+        });
+        behavior.focused = found;
+        match found {
+            Some(found) => tree.make_active(|id, _| id == found),
+            None => {
+                debug!("No tab matches route {}", self.to_hash());
+                false
+            }
+        }
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn percent_decode(s: &str) -> String {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let hex = tail
+            .get(..2)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (byte, hex) {
+            (b'%', Some(decoded)) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            (b'+', _) => {
+                bytes.push(b' ');
+                rest = tail;
+            }
+            _ => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web::WebHistory;
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::Route;
+    use crate::prelude::*;
+    use crate::tabs::{Tab, TreeBehavior};
+    use eframe::wasm_bindgen::closure::Closure;
+    use eframe::wasm_bindgen::{JsCast as _, JsValue};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// Keeps the browser history in sync with the focused tab, so back/forward move the focus.
+    pub(crate) struct WebHistory {
+        workspace: Option<String>,
+        /// Route of the current history entry.
+        current: Route,
+        popped: Arc<AtomicBool>,
+    }
+
+    impl WebHistory {
+        pub(crate) fn new(ctx: &egui::Context, current: Route) -> Option<Self> {
+            let window = web_sys::window()?;
+            let popped = Arc::new(AtomicBool::new(false));
+            let on_pop_state = {
+                let popped = popped.clone();
+                let ctx = ctx.clone();
+                Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+                    popped.store(true, Ordering::Relaxed);
+                    ctx.request_repaint();
+                })
+            };
+            window
+                .add_event_listener_with_callback("popstate", on_pop_state.as_ref().unchecked_ref())
+                .ok()?;
+            // The listener lives as long as the page.
+            on_pop_state.forget();
+            Some(Self {
+                workspace: current.workspace.clone(),
+                current,
+                popped,
+            })
+        }
+
+        /// Call once per frame, after the tiles are shown.
+        pub(crate) fn sync(
+            &mut self,
+            tree: &mut egui_tiles::Tree<Tab>,
+            behavior: &mut TreeBehavior,
+        ) {
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+
+            if self.popped.swap(false, Ordering::Relaxed) {
+                let hash = window.location().hash().unwrap_or_default();
+                let route = Route::parse(&hash);
+                // Entries without workspace are the ones from before the first push.
+                if route.workspace.is_some() && route.workspace != self.workspace {
+                    // The state of another workspace is only loaded on startup.
+                    if let Err(e) = window.location().reload() {
+                        warn!("Failed to reload for workspace change: {e:?}");
+                    }
+                    return;
+                }
+                route.apply(tree, behavior);
+                // What is actually focused now, so the next frame doesn't push a new entry.
+                self.current = Route {
+                    workspace: self.workspace.clone(),
+                    ..Route::focused(tree, behavior)
+                };
+                return;
+            }
+
+            let route = Route {
+                workspace: self.workspace.clone(),
+                ..Route::focused(tree, behavior)
+            };
+            if route.tab.is_some() && route != self.current {
+                let pushed = window.history().and_then(|history| {
+                    history.push_state_with_url(&JsValue::NULL, "", Some(&route.to_hash()))
+                });
+                if let Err(e) = pushed {
+                    warn!("Failed to update browser history: {e:?}");
+                }
+                self.current = route;
+            }
+        }
+    }
+}
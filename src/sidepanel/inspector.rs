@@ -0,0 +1,341 @@
+use crate::prelude::*;
+use crate::tabs::Tab;
+use egui_tiles::{Container, ContainerKind, Tile, TileId, Tiles, Tree};
+
+/// Side panel layout editor for the tile tree.
+///
+/// Edits are collected as [`TreeAction`]s while drawing and applied afterwards,
+/// so that the tree can be borrowed immutably during traversal.
+#[derive(Default)]
+pub(crate) struct TreeInspector {
+    /// Tile highlighted in the central panel.
+    selected: Option<TileId>,
+    renaming: Option<(TileId, String)>,
+    actions: Vec<TreeAction>,
+    /// Name of the last change applied to the tree, for the undo history.
+    edit: Option<&'static str>,
+}
+
+enum TreeAction {
+    Select(Option<TileId>),
+    SetVisible(TileId, bool),
+    SetKind(TileId, ContainerKind),
+    SetActive {
+        container: TileId,
+        child: TileId,
+    },
+    SetShare {
+        container: TileId,
+        child: TileId,
+        share: f32,
+    },
+    Rename(TileId, Option<String>),
+    Remove(TileId),
+    Move {
+        tile: TileId,
+        container: TileId,
+        index: usize,
+    },
+}
+
+impl TreeAction {
+    fn undo_name(&self) -> Option<&'static str> {
+        match self {
+            TreeAction::Select(_) => None,
+            TreeAction::SetVisible(..) => Some("action-show-hide-tile"),
+            TreeAction::SetKind(..) => Some("action-change-container-kind"),
+            TreeAction::SetActive { .. } => Some("action-select-tab"),
+            TreeAction::SetShare { .. } => Some("action-resize"),
+            TreeAction::Rename(..) => Some("action-rename-tab"),
+            TreeAction::Remove(_) => Some("action-remove-tile"),
+            TreeAction::Move { .. } => Some("action-move-tile"),
+        }
+    }
+}
+
+impl TreeInspector {
+    pub(crate) fn ui(
+        &mut self,
+        ui: &mut Ui,
+        cx: &Context,
+        behavior: &mut dyn egui_tiles::Behavior<Tab>,
+        tree: &mut Tree<Tab>,
+    ) {
+        if let Some(root) = tree.root() {
+            self.tile_ui(ui, cx, behavior, &tree.tiles, root, None);
+        }
+        for action in std::mem::take(&mut self.actions) {
+            self.edit = action.undo_name().or(self.edit);
+            self.apply(tree, action);
+        }
+    }
+
+    /// Name of the change made to the tree since the last call.
+    pub(crate) fn take_edit(&mut self) -> Option<&'static str> {
+        self.edit.take()
+    }
+
+    /// Outline the selected tile on top of the central panel.
+    pub(crate) fn highlight_selected(&self, ui: &Ui, tiles: &Tiles<Tab>) {
+        let Some(rect) = self.selected.and_then(|tile_id| tiles.rect(tile_id)) else {
+            return;
+        };
+        let stroke = egui::Stroke::new(2.0, ui.visuals().selection.stroke.color);
+        ui.painter()
+            .rect_stroke(rect.shrink(1.0), 2.0, stroke, egui::StrokeKind::Inside);
+    }
+
+    fn tile_ui(
+        &mut self,
+        ui: &mut Ui,
+        cx: &Context,
+        behavior: &mut dyn egui_tiles::Behavior<Tab>,
+        tiles: &Tiles<Tab>,
+        tile_id: TileId,
+        parent: Option<(TileId, &Container, usize)>,
+    ) {
+        let Some(tile) = tiles.get(tile_id) else {
+            debug!("Missing tile {tile_id:?}");
+            return;
+        };
+        let text = format!(
+            "{} - {tile_id:?}",
+            behavior.tab_title_for_tile(tiles, tile_id).text()
+        );
+
+        let default_open = true;
+        let toggle_name = text.clone();
+        let (toggle, header, _) = egui::collapsing_header::CollapsingState::load_with_default_open(
+            ui.ctx(),
+            ui.id().with((tile_id, "tree")),
+            default_open,
+        )
+        .show_header(ui, |ui| {
+            self.header_ui(ui, cx, tiles, tile_id, parent, text);
+        })
+        .body(|ui| {
+            if let Tile::Container(container) = tile {
+                let mut kind = container.kind();
+                egui::ComboBox::from_label(cx.tr("inspector-kind"))
+                    .selected_text(cx.tr(kind_key(kind)))
+                    .show_ui(ui, |ui| {
+                        for typ in ContainerKind::ALL {
+                            ui.selectable_value(&mut kind, typ, cx.tr(kind_key(typ)));
+                        }
+                    });
+                if kind != container.kind() {
+                    self.actions.push(TreeAction::SetKind(tile_id, kind));
+                }
+
+                for (index, &child) in container.children().enumerate() {
+                    self.tile_ui(
+                        ui,
+                        cx,
+                        behavior,
+                        tiles,
+                        child,
+                        Some((tile_id, container, index)),
+                    );
+                }
+            }
+        });
+        toggle.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &toggle_name)
+        });
+
+        // Dropping onto a container appends to it, dropping onto a pane inserts next to it.
+        if let Some(dragged) = header.response.dnd_release_payload::<TileId>() {
+            let target = match (tile, parent) {
+                (Tile::Container(container), _) => Some((tile_id, container.children().count())),
+                (Tile::Pane(_), Some((parent_id, _, index))) => Some((parent_id, index)),
+                (Tile::Pane(_), None) => None,
+            };
+            if let Some((container, index)) = target {
+                if !is_ancestor_or_self(tiles, *dragged, container) {
+                    self.actions.push(TreeAction::Move {
+                        tile: *dragged,
+                        container,
+                        index,
+                    });
+                }
+            }
+        }
+    }
+
+    fn header_ui(
+        &mut self,
+        ui: &mut Ui,
+        cx: &Context,
+        tiles: &Tiles<Tab>,
+        tile_id: TileId,
+        parent: Option<(TileId, &Container, usize)>,
+        text: String,
+    ) {
+        if let Some((renamed_id, name)) = &mut self.renaming {
+            if *renamed_id == tile_id {
+                let response = ui.text_edit_singleline(name);
+                if response.lost_focus() {
+                    if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        let name = name.trim().to_owned();
+                        let name = (!name.is_empty()).then_some(name);
+                        self.actions.push(TreeAction::Rename(tile_id, name));
+                    }
+                    self.renaming = None;
+                } else {
+                    response.request_focus();
+                }
+                return;
+            }
+        }
+
+        let is_selected = self.selected == Some(tile_id);
+        let response = ui
+            .dnd_drag_source(ui.id().with((tile_id, "drag")), tile_id, |ui| {
+                ui.selectable_label(is_selected, text)
+            })
+            .inner;
+        if response.clicked() {
+            let selected = (!is_selected).then_some(tile_id);
+            self.actions.push(TreeAction::Select(selected));
+        }
+        response.context_menu(|ui| {
+            if let Some(Tile::Pane(tab)) = tiles.get(tile_id) {
+                if ui.button(cx.tr("inspector-rename")).clicked() {
+                    let name = tab.title(cx).text().to_owned();
+                    self.renaming = Some((tile_id, name));
+                    ui.close_menu();
+                }
+                if tab.custom_title.is_some() && ui.button(cx.tr("inspector-reset-name")).clicked()
+                {
+                    self.actions.push(TreeAction::Rename(tile_id, None));
+                    ui.close_menu();
+                }
+            }
+            if ui.button(cx.tr("inspector-delete")).clicked() {
+                self.actions.push(TreeAction::Remove(tile_id));
+                ui.close_menu();
+            }
+        });
+
+        let mut visible = tiles.is_visible(tile_id);
+        if ui
+            .checkbox(&mut visible, cx.tr("inspector-visible"))
+            .changed()
+        {
+            self.actions.push(TreeAction::SetVisible(tile_id, visible));
+        }
+
+        match parent {
+            Some((container_id, Container::Tabs(tabs), _)) => {
+                let mut is_active = tabs.active == Some(tile_id);
+                if ui
+                    .radio_value(&mut is_active, true, cx.tr("inspector-active"))
+                    .clicked()
+                {
+                    self.actions.push(TreeAction::SetActive {
+                        container: container_id,
+                        child: tile_id,
+                    });
+                }
+            }
+            Some((container_id, Container::Linear(linear), _)) => {
+                let mut share = linear.shares[tile_id];
+                let response = ui
+                    .add(
+                        egui::DragValue::new(&mut share)
+                            .range(0.01..=100.0)
+                            .speed(0.01),
+                    )
+                    .on_hover_text(cx.tr("inspector-share"));
+                response.widget_info(|| {
+                    let mut info = egui::WidgetInfo::drag_value(true, share as f64);
+                    info.label = Some(cx.tr("inspector-share"));
+                    info
+                });
+                if response.changed() {
+                    self.actions.push(TreeAction::SetShare {
+                        container: container_id,
+                        child: tile_id,
+                        share,
+                    });
+                }
+            }
+            Some((_, Container::Grid(_), _)) | None => {}
+        }
+    }
+
+    fn apply(&mut self, tree: &mut Tree<Tab>, action: TreeAction) {
+        match action {
+            TreeAction::Select(tile_id) => {
+                self.selected = tile_id;
+            }
+            TreeAction::SetVisible(tile_id, visible) => {
+                tree.tiles.set_visible(tile_id, visible);
+            }
+            TreeAction::SetKind(tile_id, kind) => {
+                if let Some(Tile::Container(container)) = tree.tiles.get_mut(tile_id) {
+                    container.set_kind(kind);
+                }
+            }
+            TreeAction::SetActive { container, child } => {
+                if let Some(Tile::Container(Container::Tabs(tabs))) = tree.tiles.get_mut(container)
+                {
+                    tabs.set_active(child);
+                }
+            }
+            TreeAction::SetShare {
+                container,
+                child,
+                share,
+            } => {
+                if let Some(Tile::Container(Container::Linear(linear))) =
+                    tree.tiles.get_mut(container)
+                {
+                    linear.shares.set_share(child, share);
+                }
+            }
+            TreeAction::Rename(tile_id, name) => {
+                if let Some(Tile::Pane(tab)) = tree.tiles.get_mut(tile_id) {
+                    tab.custom_title = name;
+                }
+            }
+            TreeAction::Remove(tile_id) => {
+                debug!("Removing tile {tile_id:?} from the inspector");
+                tree.remove_recursively(tile_id);
+                if self.selected == Some(tile_id) {
+                    self.selected = None;
+                }
+            }
+            TreeAction::Move {
+                tile,
+                container,
+                index,
+            } => {
+                debug!("Moving tile {tile:?} to {container:?} at {index}");
+                tree.move_tile_to_container(tile, container, index, true);
+            }
+        }
+    }
+}
+
+/// Is `ancestor` the same tile as `tile_id` or one of its parents?
+fn is_ancestor_or_self(tiles: &Tiles<Tab>, ancestor: TileId, mut tile_id: TileId) -> bool {
+    loop {
+        if tile_id == ancestor {
+            return true;
+        }
+        match tiles.parent_of(tile_id) {
+            Some(parent) => tile_id = parent,
+            None => return false,
+        }
+    }
+}
+
+fn kind_key(kind: ContainerKind) -> &'static str {
+    match kind {
+        ContainerKind::Tabs => "inspector-kind-tabs",
+        ContainerKind::Horizontal => "inspector-kind-horizontal",
+        ContainerKind::Vertical => "inspector-kind-vertical",
+        ContainerKind::Grid => "inspector-kind-grid",
+    }
+}
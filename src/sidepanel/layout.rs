@@ -0,0 +1,50 @@
+use super::{SectionEnv, SidePanelSection};
+use crate::prelude::*;
+
+/// Tile tree behavior settings, active tiles and the tree inspector.
+pub struct LayoutSection;
+
+impl SidePanelSection for LayoutSection {
+    fn id(&self) -> &'static str {
+        "layout"
+    }
+
+    fn title(&self, cx: &Context) -> WidgetText {
+        cx.tr("section-layout").into()
+    }
+
+    fn ui(&mut self, ui: &mut Ui, env: &mut SectionEnv<'_>) {
+        env.behavior.ui(ui, env.cx);
+
+        ui.separator();
+
+        ui.collapsing(env.cx.tr("layout-active-tiles"), |ui| {
+            let active = env.tree.active_tiles();
+            for tile_id in active {
+                use egui_tiles::Behavior as _;
+                let name = env.behavior.tab_title_for_tile(&env.tree.tiles, tile_id);
+                ui.label(format!("{} - {tile_id:?}", name.text()));
+            }
+        });
+
+        if ui
+            .button(env.cx.tr("layout-copy"))
+            .on_hover_text(env.cx.tr("layout-copy-hint"))
+            .clicked()
+        {
+            match ron::ser::to_string_pretty(&*env.tree, Default::default()) {
+                Ok(layout) => ui.ctx().copy_text(layout),
+                Err(e) => error!("Failed to serialize layout: {e}"),
+            }
+        }
+
+        ui.separator();
+
+        env.inspector.ui(ui, env.cx, env.behavior, env.tree);
+    }
+
+    /// Layout editing is a development tool, hidden in release builds unless enabled.
+    fn visible_by_default(&self) -> bool {
+        cfg!(debug_assertions)
+    }
+}
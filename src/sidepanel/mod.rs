@@ -0,0 +1,208 @@
+use crate::prelude::*;
+use crate::tabs::{Tab, TreeBehavior};
+use egui::collapsing_header::CollapsingState;
+use std::collections::BTreeMap;
+
+mod inspector;
+mod layout;
+mod outline;
+
+pub(crate) use inspector::TreeInspector;
+
+/// A collapsible section of the side panel, e.g. a project explorer, outline or bookmarks.
+///
+/// Besides the built-in ones, sections are added with [`crate::TemplateApp::register_side_panel_section`].
+pub trait SidePanelSection {
+    /// Stable identifier, used to persist the order and open state of the section.
+    fn id(&self) -> &'static str;
+
+    /// Translate with [`Context::tr`].
+    fn title(&self, cx: &Context) -> WidgetText;
+
+    fn ui(&mut self, ui: &mut Ui, env: &mut SectionEnv<'_>);
+
+    /// Whether the section is shown until the user decides otherwise.
+    fn visible_by_default(&self) -> bool {
+        true
+    }
+}
+
+/// What sections get access to while drawing.
+pub struct SectionEnv<'a> {
+    pub cx: &'a mut Context,
+    pub(crate) tree: &'a mut egui_tiles::Tree<Tab>,
+    pub(crate) behavior: &'a mut TreeBehavior,
+    pub(crate) inspector: &'a mut TreeInspector,
+}
+
+/// Side panel hosting registered [`SidePanelSection`]s.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Navigator {
+    /// Section ids in display order.
+    order: Vec<String>,
+    open: BTreeMap<String, bool>,
+    visible: BTreeMap<String, bool>,
+    #[serde(skip)]
+    sections: Vec<Box<dyn SidePanelSection>>,
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        let mut navigator = Self {
+            order: Vec::new(),
+            open: BTreeMap::new(),
+            visible: BTreeMap::new(),
+            sections: Vec::new(),
+        };
+        navigator.register(Box::new(outline::OutlineSection));
+        navigator.register(Box::new(layout::LayoutSection));
+        navigator
+    }
+}
+
+enum SectionAction {
+    MoveUp(String),
+    MoveDown(String),
+}
+
+impl Navigator {
+    /// Add a section, replacing an already registered one with the same id.
+    pub fn register(&mut self, section: Box<dyn SidePanelSection>) {
+        self.sections.retain(|s| s.id() != section.id());
+        if !self.order.iter().any(|id| id == section.id()) {
+            self.order.push(section.id().to_owned());
+        }
+        self.sections.push(section);
+    }
+
+    /// Ids of the registered sections, in display order.
+    pub fn section_ids(&self) -> Vec<&'static str> {
+        let mut ids: Vec<&'static str> = self.sections.iter().map(|section| section.id()).collect();
+        ids.sort_by_key(|id| {
+            self.order
+                .iter()
+                .position(|o| o == id)
+                .unwrap_or(usize::MAX)
+        });
+        ids
+    }
+
+    /// Take over the sections of another navigator, e.g. when the state is replaced.
+    pub(crate) fn adopt_sections(&mut self, other: Navigator) {
+        for section in other.sections {
+            self.register(section);
+        }
+    }
+
+    pub(crate) fn ui(&mut self, ui: &mut Ui, env: &mut SectionEnv<'_>) {
+        self.sort_sections();
+
+        ui.horizontal(|ui| {
+            ui.strong(env.cx.tr("side-panel-navigator"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.menu_button("⚙", |ui| {
+                    for section in &self.sections {
+                        let mut visible = is_visible(&self.visible, section.as_ref());
+                        if ui.checkbox(&mut visible, section.title(env.cx)).changed() {
+                            self.visible.insert(section.id().to_owned(), visible);
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(env.cx.tr("side-panel-choose-sections"))
+                .widget_info(|| {
+                    egui::WidgetInfo::labeled(
+                        egui::WidgetType::Button,
+                        true,
+                        env.cx.tr("side-panel-choose-sections"),
+                    )
+                });
+            });
+        });
+        ui.separator();
+
+        let mut action = None;
+        for section in &mut self.sections {
+            let id = section.id();
+            if !is_visible(&self.visible, section.as_ref()) {
+                continue;
+            }
+
+            let is_open = self.open.get(id).copied().unwrap_or(true);
+            let state_id = ui.make_persistent_id(("side_panel_section", id));
+            let header = CollapsingState::load_with_default_open(ui.ctx(), state_id, is_open)
+                .show_header(ui, |ui| {
+                    ui.strong(section.title(env.cx)).context_menu(|ui| {
+                        if ui.button(env.cx.tr("side-panel-move-up")).clicked() {
+                            action = Some(SectionAction::MoveUp(id.to_owned()));
+                            ui.close_menu();
+                        }
+                        if ui.button(env.cx.tr("side-panel-move-down")).clicked() {
+                            action = Some(SectionAction::MoveDown(id.to_owned()));
+                            ui.close_menu();
+                        }
+                        if ui.button(env.cx.tr("side-panel-hide")).clicked() {
+                            self.visible.insert(id.to_owned(), false);
+                            ui.close_menu();
+                        }
+                    });
+                });
+            self.open.insert(id.to_owned(), header.is_open());
+            let (toggle, _, _) = header.body(|ui| {
+                section.ui(ui, env);
+            });
+            toggle.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, true, section.title(env.cx))
+            });
+        }
+
+        match action {
+            Some(SectionAction::MoveUp(id)) => self.move_section(&id, -1),
+            Some(SectionAction::MoveDown(id)) => self.move_section(&id, 1),
+            None => {}
+        }
+    }
+
+    /// Put sections in the persisted order, unknown ones go last.
+    fn sort_sections(&mut self) {
+        let order = &self.order;
+        self.sections.sort_by_key(|section| {
+            order
+                .iter()
+                .position(|id| id == section.id())
+                .unwrap_or(usize::MAX)
+        });
+    }
+
+    /// Swap a section with its visible neighbour in the given direction.
+    fn move_section(&mut self, id: &str, direction: isize) {
+        let visible: Vec<&str> = self
+            .sections
+            .iter()
+            .filter(|section| is_visible(&self.visible, section.as_ref()))
+            .map(|section| section.id())
+            .collect();
+        let Some(position) = visible.iter().position(|&v| v == id) else {
+            return;
+        };
+        let Some(neighbour) = position
+            .checked_add_signed(direction)
+            .and_then(|p| visible.get(p))
+        else {
+            return;
+        };
+        let a = self.order.iter().position(|o| o == id);
+        let b = self.order.iter().position(|o| o == *neighbour);
+        if let (Some(a), Some(b)) = (a, b) {
+            self.order.swap(a, b);
+        }
+    }
+}
+
+fn is_visible(visible: &BTreeMap<String, bool>, section: &dyn SidePanelSection) -> bool {
+    visible
+        .get(section.id())
+        .copied()
+        .unwrap_or(section.visible_by_default())
+}
@@ -0,0 +1,36 @@
+use super::{SectionEnv, SidePanelSection};
+use crate::prelude::*;
+use egui_tiles::Tile;
+
+/// Shows [`TabUi::outline_ui`] of the visible tabs.
+pub struct OutlineSection;
+
+impl SidePanelSection for OutlineSection {
+    fn id(&self) -> &'static str {
+        "outline"
+    }
+
+    fn title(&self, cx: &Context) -> WidgetText {
+        cx.tr("section-outline").into()
+    }
+
+    fn ui(&mut self, ui: &mut Ui, env: &mut SectionEnv<'_>) {
+        let mut is_empty = true;
+        for tile_id in env.tree.active_tiles() {
+            let Some(Tile::Pane(tab)) = env.tree.tiles.get_mut(tile_id) else {
+                continue;
+            };
+            if !tab.has_outline() {
+                continue;
+            }
+            is_empty = false;
+            ui.label(tab.title(env.cx).strong());
+            ui.push_id(tile_id, |ui| {
+                tab.outline_ui(ui, env.cx);
+            });
+        }
+        if is_empty {
+            ui.weak(env.cx.tr("outline-empty"));
+        }
+    }
+}
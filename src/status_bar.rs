@@ -0,0 +1,188 @@
+//! Items of the status bar at the bottom of the window.
+//!
+//! The app shows the focused tab, the running [`crate::tasks`], the warnings and errors logged
+//! and, in debug builds, the frame time. Tabs add their own items with
+//! [`crate::tab_viewer::TabUi::status_items`] while focused, services with [`StatusBar::set`]
+//! until they remove them again.
+
+use crate::prelude::*;
+use egui::{ProgressBar, RichText};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+type OnClick = Arc<dyn Fn(&Context) + Send + Sync>;
+
+enum StatusContent {
+    Text(String),
+    /// `None` for work of unknown length.
+    Progress(Option<f32>, String),
+    Icon(String),
+}
+
+pub struct StatusItem {
+    content: StatusContent,
+    hint: Option<String>,
+    on_click: Option<OnClick>,
+}
+
+impl StatusItem {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(StatusContent::Text(text.into()))
+    }
+
+    /// A progress bar, `fraction` goes from 0 to 1, `None` animates it.
+    pub fn progress(fraction: Option<f32>, text: impl Into<String>) -> Self {
+        Self::new(StatusContent::Progress(fraction, text.into()))
+    }
+
+    /// An icon, the hint is also its name for screen readers.
+    pub fn icon(icon: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self::new(StatusContent::Icon(icon.into())).on_hover_text(hint)
+    }
+
+    fn new(content: StatusContent) -> Self {
+        Self {
+            content,
+            hint: None,
+            on_click: None,
+        }
+    }
+
+    pub fn on_hover_text(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Make the item clickable.
+    pub fn on_click(mut self, on_click: impl Fn(&Context) + Send + Sync + 'static) -> Self {
+        self.on_click = Some(Arc::new(on_click));
+        self
+    }
+
+    /// Returns the callback if the item was clicked, to be called once the status bar is unlocked.
+    pub(crate) fn ui(&self, ui: &mut Ui) -> Option<OnClick> {
+        let sense = match self.on_click {
+            Some(_) => egui::Sense::click(),
+            None => egui::Sense::hover(),
+        };
+        let response = match &self.content {
+            StatusContent::Text(text) => ui.add(egui::Label::new(text).sense(sense)),
+            StatusContent::Progress(fraction, text) => {
+                let bar = ProgressBar::new(fraction.unwrap_or_default())
+                    .desired_width(100.0)
+                    .text(text.as_str())
+                    .animate(fraction.is_none());
+                ui.add(bar).interact(sense)
+            }
+            StatusContent::Icon(icon) => {
+                let response = ui.add(egui::Label::new(RichText::new(icon)).sense(sense));
+                if let Some(hint) = &self.hint {
+                    let typ = match self.on_click {
+                        Some(_) => egui::WidgetType::Button,
+                        None => egui::WidgetType::Label,
+                    };
+                    response.widget_info(|| egui::WidgetInfo::labeled(typ, true, hint));
+                }
+                response
+            }
+        };
+        let response = match &self.hint {
+            Some(hint) => response.on_hover_text(hint),
+            None => response,
+        };
+        let response = match self.on_click {
+            Some(_) => response.on_hover_cursor(egui::CursorIcon::PointingHand),
+            None => response,
+        };
+        if response.clicked() {
+            self.on_click.clone()
+        } else {
+            None
+        }
+    }
+}
+
+/// Warnings and errors logged since the user last looked at the log.
+#[derive(Default)]
+struct LogCounts {
+    warnings: AtomicUsize,
+    errors: AtomicUsize,
+}
+
+#[derive(Default)]
+pub struct StatusBar {
+    /// Items of the services, in the order they were first set.
+    items: Vec<(String, StatusItem)>,
+    log_counts: Arc<LogCounts>,
+}
+
+impl StatusBar {
+    /// Show an item until it is removed, replaces the item with the same id.
+    pub fn set(&mut self, id: impl Into<String>, item: StatusItem) {
+        let id = id.into();
+        match self.items.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) => *existing = item,
+            None => self.items.push((id, item)),
+        }
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.items.retain(|(existing, _)| existing != id);
+    }
+
+    pub fn items(&self) -> impl DoubleEndedIterator<Item = &StatusItem> {
+        self.items.iter().map(|(_, item)| item)
+    }
+
+    /// Warnings and errors logged since [`StatusBar::reset_log_counts`]. Native only.
+    pub fn log_counts(&self) -> (usize, usize) {
+        (
+            self.log_counts.warnings.load(Ordering::Relaxed),
+            self.log_counts.errors.load(Ordering::Relaxed),
+        )
+    }
+
+    pub fn reset_log_counts(&self) {
+        self.log_counts.warnings.store(0, Ordering::Relaxed);
+        self.log_counts.errors.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use log_layer::LogCountLayer;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod log_layer {
+    use super::LogCounts;
+    use crate::context::Context;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    /// `tracing` layer counting the warnings and errors for [`super::StatusBar::log_counts`].
+    pub struct LogCountLayer {
+        counts: Arc<LogCounts>,
+    }
+
+    impl LogCountLayer {
+        pub fn new(cx: &Context) -> Self {
+            Self {
+                counts: cx.status_bar().log_counts.clone(),
+            }
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for LogCountLayer {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let count = match *event.metadata().level() {
+                tracing::Level::ERROR => &self.counts.errors,
+                tracing::Level::WARN => &self.counts.warnings,
+                _ => return,
+            };
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
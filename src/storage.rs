@@ -0,0 +1,322 @@
+//! Writing the persisted state, and what to do when that fails.
+//!
+//! eframe ignores errors when writing to `localStorage` on the web, so there the state is written
+//! here directly to find out about exceeded quotas.
+
+use crate::prelude::*;
+use egui::{Align2, RichText};
+
+/// Why the state could not be written.
+#[derive(Debug)]
+pub(crate) struct SaveError {
+    pub message: String,
+    /// The browser ran out of storage space for this site.
+    pub quota_exceeded: bool,
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.quota_exceeded {
+            write!(f, "storage quota exceeded ({})", self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+/// A stored value, as shown in the Debug window.
+pub(crate) struct StorageEntry {
+    pub key: String,
+    /// Approximate size in bytes, as counted against the quota on the web.
+    pub size: usize,
+}
+
+/// Write `value` under `key`, reporting errors that [`eframe::Storage`] would swallow.
+pub(crate) fn set_string(
+    storage: &mut dyn eframe::Storage,
+    key: &str,
+    value: &str,
+) -> Result<(), SaveError> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = storage;
+        web::set_item(key, value)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        storage.set_string(key, value.to_owned());
+        Ok(())
+    }
+}
+
+/// Read a value from `localStorage` outside of [`eframe::Storage`], e.g. a project layout.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn get_item(key: &str) -> Result<Option<String>, String> {
+    web::get_item(key)
+}
+
+/// Write a value to `localStorage` outside of [`eframe::Storage`].
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn set_item(key: &str, value: &str) -> Result<(), SaveError> {
+    web::set_item(key, value)
+}
+
+/// Everything stored by the app, including egui memory and other workspaces.
+pub(crate) fn entries() -> Result<Vec<StorageEntry>, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web::entries()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = file_path().ok_or("no storage directory on this platform")?;
+        let ron = std::fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        let values: std::collections::BTreeMap<String, String> =
+            ron::from_str(&ron).map_err(|e| format!("failed to parse {}: {e}", path.display()))?;
+        Ok(values
+            .into_iter()
+            .map(|(key, value)| StorageEntry {
+                size: key.len() + value.len(),
+                key,
+            })
+            .collect())
+    }
+}
+
+/// File eframe persists the state to.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn file_path() -> Option<std::path::PathBuf> {
+    eframe::storage_dir(crate::APP_NAME).map(|dir| dir.join("app.ron"))
+}
+
+/// Hand data to the user: a download on the web, a file in the working directory natively.
+///
+/// Returns where the data went, translated.
+pub(crate) fn export(
+    cx: &Context,
+    kind: &str,
+    extension: &str,
+    data: &str,
+) -> Result<String, String> {
+    let secs = unix_secs();
+    let file_name = format!("eframe_template_{kind}_{secs}.{extension}");
+    #[cfg(target_arch = "wasm32")]
+    {
+        web::download(&file_name, data)?;
+        Ok(cx.tr_args("export-downloaded", &[("file", &file_name)]))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::fs::write(&file_name, data).map_err(|e| e.to_string())?;
+        Ok(cx.tr_args("export-saved", &[("file", &file_name)]))
+    }
+}
+
+pub(crate) fn unix_secs() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        (js_sys::Date::now() / 1000.0) as u64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+enum Problem {
+    Load(String),
+    Save(SaveError),
+}
+
+/// Non-blocking notice about state that could not be loaded or saved, offering to export it.
+pub(crate) struct StorageNotice {
+    problem: Problem,
+    /// The serialized state in question.
+    data: String,
+    exported: Option<Result<String, String>>,
+}
+
+impl StorageNotice {
+    pub(crate) fn load_failed(error: impl std::fmt::Display, saved: String) -> Self {
+        Self {
+            problem: Problem::Load(error.to_string()),
+            data: saved,
+            exported: None,
+        }
+    }
+
+    pub(crate) fn save_failed(error: SaveError, serialized: String) -> Self {
+        Self {
+            problem: Problem::Save(error),
+            data: serialized,
+            exported: None,
+        }
+    }
+
+    /// Unreadable saved state is not overwritten until the user had a chance to export it.
+    pub(crate) fn blocks_saving(&self) -> bool {
+        matches!(self.problem, Problem::Load(_))
+    }
+
+    /// Returns `false` once the notice is dismissed.
+    pub(crate) fn show(&mut self, ctx: &egui::Context, cx: &Context) -> bool {
+        let mut keep = true;
+        egui::Window::new(cx.tr("storage-title"))
+            .id(egui::Id::new("storage_notice"))
+            .anchor(Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.set_max_width(320.0);
+                match &self.problem {
+                    Problem::Load(e) => {
+                        ui.label(
+                            RichText::new(cx.tr("storage-load-failed"))
+                                .color(ui.visuals().warn_fg_color),
+                        );
+                        ui.label(cx.tr_args("storage-load-failed-default", &[("error", e)]));
+                        ui.weak(cx.tr("storage-load-failed-kept"));
+                    }
+                    Problem::Save(e) => {
+                        ui.label(
+                            RichText::new(cx.tr("storage-save-failed"))
+                                .color(ui.visuals().error_fg_color),
+                        );
+                        ui.label(e.to_string());
+                        if e.quota_exceeded {
+                            ui.weak(cx.tr("storage-quota-exceeded"));
+                        }
+                    }
+                }
+                ui.label(cx.tr_args("storage-size", &[("bytes", &self.data.len().to_string())]));
+
+                match &self.exported {
+                    Some(Ok(location)) => {
+                        ui.label(cx.tr_args("storage-exported", &[("location", location)]));
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(
+                            ui.visuals().error_fg_color,
+                            cx.tr_args("storage-export-failed", &[("error", e)]),
+                        );
+                    }
+                    None => {}
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.button(cx.tr("storage-export")).clicked() {
+                        self.exported = Some(export(cx, "state", "ron", &self.data));
+                    }
+                    if ui
+                        .button(cx.tr("storage-copy"))
+                        .on_hover_text(cx.tr("storage-copy-hint"))
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(self.data.clone());
+                    }
+                    let dismiss = match self.problem {
+                        Problem::Load(_) => ui
+                            .button(cx.tr("storage-discard"))
+                            .on_hover_text(cx.tr("storage-discard-hint")),
+                        Problem::Save(_) => ui.button(cx.tr("storage-dismiss")),
+                    };
+                    if dismiss.clicked() {
+                        keep = false;
+                    }
+                });
+            });
+        keep
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::{SaveError, StorageEntry};
+    use eframe::wasm_bindgen::{JsCast as _, JsValue};
+
+    fn local_storage() -> Result<web_sys::Storage, String> {
+        web_sys::window()
+            .ok_or("no window")?
+            .local_storage()
+            .map_err(describe)?
+            .ok_or_else(|| "localStorage is not available".to_owned())
+    }
+
+    fn describe(e: JsValue) -> String {
+        match e.dyn_ref::<web_sys::DomException>() {
+            Some(e) => format!("{}: {}", e.name(), e.message()),
+            None => format!("{e:?}"),
+        }
+    }
+
+    pub(super) fn get_item(key: &str) -> Result<Option<String>, String> {
+        local_storage()?.get_item(key).map_err(describe)
+    }
+
+    pub(super) fn set_item(key: &str, value: &str) -> Result<(), SaveError> {
+        let storage = local_storage().map_err(|message| SaveError {
+            message,
+            quota_exceeded: false,
+        })?;
+        storage.set_item(key, value).map_err(|e| {
+            // Firefox used to report its own name for the same thing.
+            let quota_exceeded = e.dyn_ref::<web_sys::DomException>().is_some_and(|e| {
+                matches!(
+                    e.name().as_str(),
+                    "QuotaExceededError" | "NS_ERROR_DOM_QUOTA_REACHED"
+                )
+            });
+            SaveError {
+                message: describe(e),
+                quota_exceeded,
+            }
+        })
+    }
+
+    pub(super) fn entries() -> Result<Vec<StorageEntry>, String> {
+        let storage = local_storage()?;
+        let len = storage.length().map_err(describe)?;
+        let mut entries = Vec::new();
+        for i in 0..len {
+            let Some(key) = storage.key(i).map_err(describe)? else {
+                continue;
+            };
+            let value = storage
+                .get_item(&key)
+                .map_err(describe)?
+                .unwrap_or_default();
+            // Browsers store UTF-16.
+            let size = 2 * (key.encode_utf16().count() + value.encode_utf16().count());
+            entries.push(StorageEntry { key, size });
+        }
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(entries)
+    }
+
+    pub(super) fn download(file_name: &str, data: &str) -> Result<(), String> {
+        let parts = js_sys::Array::of1(&JsValue::from_str(data));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("text/plain");
+        let blob =
+            web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(describe)?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(describe)?;
+
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("no document")?;
+        let anchor = document
+            .create_element("a")
+            .map_err(describe)?
+            .dyn_into::<web_sys::HtmlAnchorElement>()
+            .map_err(|_| "not an anchor element")?;
+        anchor.set_href(&url);
+        anchor.set_download(file_name);
+        anchor.click();
+
+        web_sys::Url::revoke_object_url(&url).map_err(describe)
+    }
+}
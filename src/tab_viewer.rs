@@ -1,10 +1,39 @@
 use crate::context::Context;
+use crate::file_drop::{FileTypes, OpenedFile};
+use crate::status_bar::StatusItem;
 use egui::{Ui, WidgetText};
 
 pub trait TabUi {
-    fn title(&self) -> WidgetText;
+    /// Translate with [`Context::tr`].
+    fn title(&self, cx: &Context) -> WidgetText;
     fn ui(&mut self, ui: &mut Ui, cx: &mut Context);
     fn is_closeable(&self) -> bool;
+
+    /// Whether [`TabUi::outline_ui`] has anything to show in the side panel.
+    fn has_outline(&self) -> bool {
+        false
+    }
+
+    /// Content of the side panel "Outline" section while this tab is visible.
+    fn outline_ui(&mut self, _ui: &mut Ui, _cx: &mut Context) {}
+
+    /// Items for the status bar while this tab is focused.
+    fn status_items(&self, _cx: &Context) -> Vec<StatusItem> {
+        Vec::new()
+    }
+
+    /// Files dropped on the app that open in a new tab of this kind, see [`crate::file_drop`].
+    fn file_types() -> FileTypes
+    where
+        Self: Sized,
+    {
+        FileTypes::NONE
+    }
+
+    /// Load a dropped file matching [`TabUi::file_types`] into a new tab.
+    fn open_file(&mut self, _file: &OpenedFile) -> Result<(), String> {
+        Err("this kind of tab doesn't open files".to_owned())
+    }
 }
 
 // pub struct AppTabViewer<'a, 'b> {
@@ -1,11 +1,12 @@
 use crate::context::Context;
+use crate::file_drop::{FileTypes, OpenedFile};
 use crate::prelude::*;
 use crate::tab_viewer::TabUi;
 use egui::{Ui, WidgetText};
 use egui_tiles::{SimplificationOptions, Tile, TileId, Tiles, UiResponse};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
-use strum::{AsRefStr, EnumDiscriminants, EnumIter};
+use strum::{AsRefStr, EnumDiscriminants, EnumIter, IntoEnumIterator};
 
 pub mod tab_a;
 pub mod tab_b;
@@ -17,15 +18,40 @@ pub enum TabKind {
     TabB(tab_b::TabB),
 }
 
-// impl TabKindDiscriminants {
-//     pub fn create_tab(&self, nr: usize) -> Tab {
-//         let kind = match self {
-//             TabKindDiscriminants::TabA => TabKind::TabA(tab_a::TabA::default()),
-//             TabKindDiscriminants::TabB => TabKind::TabB(tab_b::TabB::default()),
-//         };
-//         Tab { kind, nr }
-//     }
-// }
+impl TabKindDiscriminants {
+    pub fn create_tab(&self, nr: usize) -> Tab {
+        let kind = match self {
+            TabKindDiscriminants::TabA => TabKind::TabA(tab_a::TabA::default()),
+            TabKindDiscriminants::TabB => TabKind::TabB(tab_b::TabB::default()),
+        };
+        Tab {
+            kind,
+            nr,
+            custom_title: None,
+            failure: None,
+        }
+    }
+
+    /// Translation key of the title of new tabs of this kind.
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            TabKindDiscriminants::TabA => "tab-a",
+            TabKindDiscriminants::TabB => "tab-b",
+        }
+    }
+
+    pub fn file_types(&self) -> FileTypes {
+        match self {
+            TabKindDiscriminants::TabA => tab_a::TabA::file_types(),
+            TabKindDiscriminants::TabB => tab_b::TabB::file_types(),
+        }
+    }
+
+    /// The first kind of tab that opens the file, see [`crate::file_drop`].
+    pub fn for_file(name: &str, mime: &str) -> Option<Self> {
+        Self::iter().find(|kind| kind.file_types().matches(name, mime))
+    }
+}
 
 impl Debug for TabKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -37,6 +63,38 @@ impl Debug for TabKind {
 pub struct Tab {
     pub kind: TabKind,
     pub nr: usize,
+    /// Title set by the user in the tree inspector or the name of the file the tab was opened
+    /// with, overrides [`TabUi::title`].
+    #[serde(default)]
+    pub custom_title: Option<String>,
+    /// Set when the UI of the tab panicked, an error card is shown instead until reset.
+    #[serde(skip)]
+    pub failure: Option<TabFailure>,
+}
+
+/// Why a tab stopped rendering.
+#[derive(Debug)]
+pub struct TabFailure {
+    pub message: String,
+    /// Panic details for bug reports.
+    pub report: String,
+}
+
+/// Run the UI of a tab, turning a panic into a [`TabFailure`] so the rest of the app keeps running.
+///
+/// On the web panics abort, so there is nothing to catch.
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, TabFailure> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        crate::crash::catch(f).map_err(|report| TabFailure {
+            report: report.to_text(),
+            message: report.message,
+        })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Ok(f())
+    }
 }
 
 impl Tab {
@@ -78,10 +136,13 @@ impl Tab {
         UiResponse::None
     }
 
-    pub fn title(&self) -> WidgetText {
+    pub fn title(&self, cx: &Context) -> WidgetText {
+        if let Some(title) = &self.custom_title {
+            return title.clone().into();
+        }
         match &self.kind {
-            TabKind::TabA(t) => t.title(),
-            TabKind::TabB(t) => t.title(),
+            TabKind::TabA(t) => t.title(cx),
+            TabKind::TabB(t) => t.title(cx),
         }
     }
 
@@ -91,13 +152,70 @@ impl Tab {
             TabKind::TabB(t) => t.is_closeable(),
         }
     }
+
+    /// Replace the state of the tab with a snapshot pushed by
+    /// [`crate::undo::UndoHistory::push_tab`].
+    pub fn restore(&mut self, snapshot: &str) -> Result<(), ron::error::SpannedError> {
+        match &mut self.kind {
+            TabKind::TabA(t) => *t = ron::from_str(snapshot)?,
+            TabKind::TabB(t) => *t = ron::from_str(snapshot)?,
+        }
+        Ok(())
+    }
+
+    pub fn has_outline(&self) -> bool {
+        match &self.kind {
+            TabKind::TabA(t) => t.has_outline(),
+            TabKind::TabB(t) => t.has_outline(),
+        }
+    }
+
+    pub fn outline_ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        match &mut self.kind {
+            TabKind::TabA(t) => t.outline_ui(ui, cx),
+            TabKind::TabB(t) => t.outline_ui(ui, cx),
+        }
+    }
+
+    pub fn status_items(&self, cx: &Context) -> Vec<StatusItem> {
+        match &self.kind {
+            TabKind::TabA(t) => t.status_items(cx),
+            TabKind::TabB(t) => t.status_items(cx),
+        }
+    }
+
+    pub fn open_file(&mut self, file: &OpenedFile) -> Result<(), String> {
+        match &mut self.kind {
+            TabKind::TabA(t) => t.open_file(file)?,
+            TabKind::TabB(t) => t.open_file(file)?,
+        }
+        self.custom_title = Some(file.name.clone());
+        Ok(())
+    }
 }
 
 pub struct TreeBehavior {
     simplification_options: egui_tiles::SimplificationOptions,
     tab_bar_height: f32,
     gap_width: f32,
-    pub(crate) add_child_to: Option<TileId>,
+    pub(crate) add_child_to: Option<(TileId, TabKindDiscriminants)>,
+    /// Pane the user last clicked into or selected the tab of.
+    pub(crate) focused: Option<TileId>,
+    /// Pane to remove, requested from the error card of a failed tab.
+    pub(crate) close_pane: Option<TileId>,
+    /// Name of the layout change made this frame, for the undo history.
+    pub(crate) layout_edit: Option<&'static str>,
+    /// A share is being dragged, it becomes one undo entry when the pointer is released.
+    pub(crate) resizing: bool,
+    /// The active tab changed this frame, the undo snapshot needs it but no entry of its own.
+    pub(crate) tab_selected: bool,
+    /// Pane to move the keyboard focus to, see [`TreeBehavior::focus_next_pane`].
+    pub(crate) focus_request: Option<TileId>,
+    /// Pane under the pointer while files are dragged over or dropped on the window.
+    pub(crate) drop_target: Option<TileId>,
+    /// Pane to show alone, requested by double-clicking its tab.
+    pub(crate) maximize_request: Option<TileId>,
+    /// Set by [`TreeBehavior::feed_cx`] once the state is attached, nothing is drawn before.
     cx: Option<Context>,
     show_view_numbers: bool,
 }
@@ -116,6 +234,14 @@ impl Default for TreeBehavior {
             tab_bar_height: 20.0,
             gap_width: 2.0,
             add_child_to: None,
+            focused: None,
+            close_pane: None,
+            layout_edit: None,
+            resizing: false,
+            tab_selected: false,
+            focus_request: None,
+            drop_target: None,
+            maximize_request: None,
             cx: None,
             show_view_numbers: false,
         }
@@ -123,44 +249,58 @@ impl Default for TreeBehavior {
 }
 
 impl TreeBehavior {
-    pub(crate) fn ui(&mut self, ui: &mut Ui) {
+    pub(crate) fn ui(&mut self, ui: &mut Ui, cx: &Context) {
         let Self {
             simplification_options,
             tab_bar_height,
             gap_width,
             add_child_to: _,
+            focused: _,
+            close_pane: _,
+            layout_edit: _,
+            resizing: _,
+            tab_selected: _,
+            focus_request: _,
+            drop_target: _,
+            maximize_request: _,
             cx: _,
             show_view_numbers: _,
         } = self;
 
+        // The grid labels double as the names of the widgets for screen readers.
         egui::Grid::new("behavior_ui")
             .num_columns(2)
             .show(ui, |ui| {
-                ui.label("All panes must have tabs:");
-                ui.checkbox(&mut simplification_options.all_panes_must_have_tabs, "");
+                let label = ui.label(cx.tr("layout-all-panes-have-tabs"));
+                ui.checkbox(&mut simplification_options.all_panes_must_have_tabs, "")
+                    .labelled_by(label.id);
                 ui.end_row();
 
-                ui.label("Join nested containers:");
+                let label = ui.label(cx.tr("layout-join-nested"));
                 ui.checkbox(
                     &mut simplification_options.join_nested_linear_containers,
                     "",
-                );
+                )
+                .labelled_by(label.id);
                 ui.end_row();
 
-                ui.label("Tab bar height:");
+                let label = ui.label(cx.tr("layout-tab-bar-height"));
                 ui.add(
                     egui::DragValue::new(tab_bar_height)
                         .range(0.0..=100.0)
                         .speed(1.0),
-                );
+                )
+                .labelled_by(label.id);
                 ui.end_row();
 
-                ui.label("Gap width:");
-                ui.add(egui::DragValue::new(gap_width).range(0.0..=20.0).speed(1.0));
+                let label = ui.label(cx.tr("layout-gap-width"));
+                ui.add(egui::DragValue::new(gap_width).range(0.0..=20.0).speed(1.0))
+                    .labelled_by(label.id);
                 ui.end_row();
 
-                ui.label("Show view numbers:");
-                ui.checkbox(&mut self.show_view_numbers, "");
+                let label = ui.label(cx.tr("layout-show-view-numbers"));
+                ui.checkbox(&mut self.show_view_numbers, "")
+                    .labelled_by(label.id);
                 ui.end_row();
             });
     }
@@ -168,25 +308,174 @@ impl TreeBehavior {
     pub fn feed_cx(&mut self, cx: Context) {
         self.cx = Some(cx);
     }
+
+    /// Title of a pane, the name of its kind until there is a context to translate with.
+    fn pane_title(&self, view: &Tab) -> WidgetText {
+        match &self.cx {
+            Some(cx) => view.title(cx),
+            None => TabKindDiscriminants::from(&view.kind)
+                .as_ref()
+                .to_owned()
+                .into(),
+        }
+    }
+
+    /// Move the keyboard focus to the next visible pane after the focused one, or the previous.
+    pub(crate) fn focus_next_pane(&mut self, tree: &egui_tiles::Tree<Tab>, backwards: bool) {
+        let panes: Vec<TileId> = tree
+            .active_tiles()
+            .into_iter()
+            .filter(|tile_id| matches!(tree.tiles.get(*tile_id), Some(Tile::Pane(_))))
+            .collect();
+        let Some(last) = panes.len().checked_sub(1) else {
+            return;
+        };
+        let current = self
+            .focused
+            .and_then(|focused| panes.iter().position(|tile_id| *tile_id == focused));
+        let next = match (current, backwards) {
+            (Some(index), true) if index > 0 => index - 1,
+            (_, true) => last,
+            (Some(index), false) if index < last => index + 1,
+            (_, false) => 0,
+        };
+        self.focused = Some(panes[next]);
+        self.focus_request = Some(panes[next]);
+    }
+
+    /// A focusable node covering the pane, so the keyboard focus can move between panes and
+    /// screen readers announce which tab it is in.
+    fn pane_focus_ui(&mut self, ui: &mut Ui, tile_id: TileId, title: &str) {
+        let response = ui.interact(
+            ui.max_rect(),
+            ui.id().with("pane_focus"),
+            egui::Sense::focusable_noninteractive(),
+        );
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, title));
+        #[cfg(feature = "accessibility")]
+        ui.ctx().accesskit_node_builder(response.id, |node| {
+            node.set_role(egui::accesskit::Role::Pane);
+        });
+        if self.focus_request == Some(tile_id) {
+            self.focus_request = None;
+            response.request_focus();
+        }
+        if response.has_focus() {
+            self.focused = Some(tile_id);
+            ui.painter().rect_stroke(
+                ui.max_rect().shrink(1.0),
+                0.0,
+                ui.visuals().selection.stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+    }
+
+    /// Error card shown in place of a tab whose UI panicked.
+    fn failure_ui(&mut self, ui: &mut Ui, tile_id: TileId, view: &mut Tab) {
+        let (Some(failure), Some(cx)) = (&view.failure, self.cx.clone()) else {
+            return;
+        };
+        let mut reset = false;
+        ui.vertical_centered(|ui| {
+            ui.add_space(16.0);
+            egui::Frame::group(ui.style())
+                .inner_margin(8.0)
+                .show(ui, |ui| {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        cx.tr_args("tab-failed", &[("title", self.pane_title(view).text())]),
+                    );
+                    ui.label(&failure.message);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(cx.tr("tab-reset"))
+                            .on_hover_text(cx.tr("tab-reset-hint"))
+                            .clicked()
+                        {
+                            reset = true;
+                        }
+                        if view.is_closeable() && ui.button(cx.tr("tab-close")).clicked() {
+                            self.close_pane = Some(tile_id);
+                        }
+                        if ui.button(cx.tr("tab-copy-report")).clicked() {
+                            ui.ctx().copy_text(failure.report.clone());
+                        }
+                    });
+                });
+        });
+        if reset {
+            debug!("Resetting failed tab {tile_id:?}");
+            view.kind = TabKindDiscriminants::from(&view.kind)
+                .create_tab(view.nr)
+                .kind;
+            view.failure = None;
+        }
+    }
 }
 
 impl egui_tiles::Behavior<Tab> for TreeBehavior {
-    fn pane_ui(&mut self, ui: &mut Ui, _tile_id: TileId, view: &mut Tab) -> UiResponse {
-        if let Some(cx) = &mut self.cx {
-            view.ui(ui, cx, view.title().text())
-        } else {
-            UiResponse::None
+    fn pane_ui(&mut self, ui: &mut Ui, tile_id: TileId, view: &mut Tab) -> UiResponse {
+        let title = self.pane_title(view).text().to_owned();
+        profile_scope!("pane_ui", &title);
+        if ui.input(|i| i.pointer.any_pressed()) && ui.rect_contains_pointer(ui.max_rect()) {
+            self.focused = Some(tile_id);
+        }
+        let files_dragged =
+            ui.input(|i| !i.raw.hovered_files.is_empty() || !i.raw.dropped_files.is_empty());
+        if files_dragged && ui.rect_contains_pointer(ui.max_rect()) {
+            self.drop_target = Some(tile_id);
+        }
+        self.pane_focus_ui(ui, tile_id, &title);
+        if view.failure.is_some() {
+            self.failure_ui(ui, tile_id, view);
+            return UiResponse::None;
+        }
+        let Some(cx) = &mut self.cx else {
+            return UiResponse::None;
+        };
+        cx.undo().set_current_pane(Some(tile_id));
+        let result = catch_panic(|| view.ui(ui, cx, &title));
+        cx.undo().set_current_pane(None);
+        match result {
+            Ok(response) => response,
+            Err(failure) => {
+                error!("Tab {title} panicked: {}", failure.message);
+                view.failure = Some(failure);
+                ui.ctx().request_repaint();
+                UiResponse::None
+            }
         }
     }
 
     fn tab_title_for_pane(&mut self, view: &Tab) -> WidgetText {
         if self.show_view_numbers {
-            format!("{}: {}", view.nr, view.title().text()).into()
+            format!("{}: {}", view.nr, self.pane_title(view).text()).into()
         } else {
-            view.title()
+            self.pane_title(view)
         }
     }
 
+    fn on_tab_button(
+        &mut self,
+        tiles: &Tiles<Tab>,
+        tile_id: TileId,
+        button_response: egui::Response,
+    ) -> egui::Response {
+        if matches!(tiles.get(tile_id), Some(Tile::Pane(_))) {
+            if button_response.clicked() {
+                self.focused = Some(tile_id);
+            }
+            if button_response.double_clicked() {
+                self.maximize_request = Some(tile_id);
+            }
+        }
+        let title = self.tab_title_for_tile(tiles, tile_id).text().to_owned();
+        button_response
+            .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &title));
+        button_response
+    }
+
     fn is_tab_closable(&self, tiles: &Tiles<Tab>, tile_id: TileId) -> bool {
         if let Some(tile) = tiles.get(tile_id) {
             match tile {
@@ -223,10 +512,21 @@ impl egui_tiles::Behavior<Tab> for TreeBehavior {
             }
         }
 
+        self.layout_edit = Some("action-close-tab");
+
         // Proceed to removing the tab
         true
     }
 
+    fn on_edit(&mut self, edit_action: egui_tiles::EditAction) {
+        match edit_action {
+            egui_tiles::EditAction::TileDropped => self.layout_edit = Some("action-move-tile"),
+            egui_tiles::EditAction::TileResized => self.resizing = true,
+            egui_tiles::EditAction::TabSelected => self.tab_selected = true,
+            egui_tiles::EditAction::TileDragged => {}
+        }
+    }
+
     fn top_bar_right_ui(
         &mut self,
         _tiles: &Tiles<Tab>,
@@ -235,10 +535,26 @@ impl egui_tiles::Behavior<Tab> for TreeBehavior {
         _tabs: &egui_tiles::Tabs,
         _scroll_offset: &mut f32,
     ) {
+        let Some(cx) = &self.cx else {
+            return;
+        };
+        let name = cx.tr("tab-add");
         ui.add_space(4.0);
-        if ui.button("➕").clicked() {
-            self.add_child_to = Some(tile_id);
-        }
+        ui.menu_button("➕", |ui| {
+            for kind in TabKindDiscriminants::iter() {
+                let kind_title = cx.tr(kind.title_key());
+                if ui
+                    .button(cx.tr_args("tab-new", &[("kind", &kind_title)]))
+                    .clicked()
+                {
+                    self.add_child_to = Some((tile_id, kind));
+                    ui.close_menu();
+                }
+            }
+        })
+        .response
+        .on_hover_text(&name)
+        .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &name));
     }
 
     fn tab_bar_height(&self, _style: &egui::Style) -> f32 {
@@ -1,3 +1,4 @@
+use crate::file_drop::{FileTypes, OpenedFile};
 use crate::prelude::*;
 
 #[derive(Default, Serialize, Deserialize)]
@@ -6,14 +7,25 @@ pub struct TabA {
 }
 
 impl TabUi for TabA {
-    fn title(&self) -> WidgetText {
-        "Tab A".into()
+    fn title(&self, cx: &Context) -> WidgetText {
+        cx.tr("tab-a").into()
     }
 
-    fn ui(&mut self, ui: &mut Ui, _cx: &mut Context) {
-        ui.text_edit_singleline(&mut self.s);
+    fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let before = self.s.clone();
+        ui.horizontal(|ui| {
+            let label = ui.label(cx.tr("tab-a-text"));
+            if ui
+                .text_edit_singleline(&mut self.s)
+                .labelled_by(label.id)
+                .changed()
+            {
+                cx.undo()
+                    .push_tab("action-edit-text", &TabA { s: before }, self);
+            }
+        });
 
-        if ui.button("Log").clicked() {
+        if ui.button(cx.tr("tab-log")).clicked() {
             info!("Button in TabA");
         }
     }
@@ -21,4 +33,28 @@ impl TabUi for TabA {
     fn is_closeable(&self) -> bool {
         true
     }
+
+    fn file_types() -> FileTypes {
+        FileTypes {
+            extensions: &["txt", "md", "log"],
+            mime_types: &["text/*"],
+        }
+    }
+
+    fn open_file(&mut self, file: &OpenedFile) -> Result<(), String> {
+        self.s = file.text()?.to_owned();
+        Ok(())
+    }
+
+    fn has_outline(&self) -> bool {
+        true
+    }
+
+    fn outline_ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let count = self.s.chars().count().to_string();
+        ui.label(cx.tr_args("tab-a-characters", &[("count", &count)]));
+        for word in self.s.split_whitespace() {
+            ui.monospace(word);
+        }
+    }
 }
@@ -1,24 +1,74 @@
 use crate::prelude::*;
 
+/// Numbers [`count_primes`] goes through, takes a few seconds.
+const PRIME_LIMIT: u64 = 5_000_000;
+
 #[derive(Default, Serialize, Deserialize)]
 pub struct TabB {
     a: f32,
 }
 
 impl TabUi for TabB {
-    fn title(&self) -> WidgetText {
-        "Tab B".into()
+    fn title(&self, cx: &Context) -> WidgetText {
+        cx.tr("tab-b").into()
     }
 
-    fn ui(&mut self, ui: &mut Ui, _cx: &mut Context) {
-        ui.add(egui::Slider::new(&mut self.a, 0.0..=10.0));
+    fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let before = self.a;
+        if ui
+            .add(egui::Slider::new(&mut self.a, 0.0..=10.0).text(cx.tr("tab-b-value")))
+            .changed()
+        {
+            cx.undo()
+                .push_tab("action-change-value", &TabB { a: before }, self);
+        }
 
-        if ui.button("Log").clicked() {
+        if ui.button(cx.tr("tab-log")).clicked() {
             info!("Button in TabB");
         }
+        if ui.button(cx.tr("tab-b-notify")).clicked() {
+            let value = format!("{:.1}", self.a);
+            cx.notify(
+                NotifyLevel::Info,
+                cx.tr_args("tab-b-slider-at", &[("value", &value)]),
+            );
+        }
+        if ui.button(cx.tr("tab-b-count-primes")).clicked() {
+            let limit = PRIME_LIMIT.to_string();
+            let name = cx.tr_args("tab-b-count-primes-task", &[("limit", &limit)]);
+            let task_cx = cx.clone();
+            cx.spawn_task(name, move |task| {
+                let count = count_primes(task, PRIME_LIMIT)?.to_string();
+                Ok(task_cx.tr_args("tab-b-primes", &[("count", &count)]))
+            });
+        }
     }
 
     fn is_closeable(&self) -> bool {
         true
     }
+
+    fn status_items(&self, cx: &Context) -> Vec<StatusItem> {
+        vec![
+            StatusItem::progress(Some(self.a / 10.0), format!("{:.1}", self.a))
+                .on_hover_text(cx.tr("tab-b-slider-value")),
+        ]
+    }
+}
+
+/// Deliberately slow, to have something to watch in the Tasks window.
+fn count_primes(task: &TaskHandle, limit: u64) -> Result<u64, String> {
+    let mut count = 0;
+    for n in 2..limit {
+        if n % 10_000 == 0 {
+            if task.is_cancelled() {
+                return Err("cancelled".to_owned());
+            }
+            task.set_progress(Some(n as f32 / limit as f32));
+        }
+        if (2..).take_while(|d| d * d <= n).all(|d| n % d != 0) {
+            count += 1;
+        }
+    }
+    Ok(count)
 }
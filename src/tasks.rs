@@ -0,0 +1,303 @@
+//! Work running off the UI thread, spawned with [`Context::spawn_task`] and listed in the Tasks
+//! window.
+//!
+//! Cancelling is cooperative: the work checks [`TaskHandle::is_cancelled`] every now and then and
+//! returns early. On the web there are no threads, the work runs on the main thread after the
+//! current frame and blocks the page until it returns.
+
+use crate::prelude::*;
+use egui::mutex::Mutex;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// How many finished tasks are kept for the Tasks window.
+const HISTORY_LEN: usize = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskId(u64);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaskOutcome {
+    Done(String),
+    Failed(String),
+    Cancelled,
+}
+
+/// What the task and the UI share.
+#[derive(Default)]
+struct Shared {
+    cancelled: AtomicBool,
+    /// From 0 to 1, `None` while the length of the work isn't known.
+    progress: Mutex<Option<f32>>,
+    outcome: Mutex<Option<TaskOutcome>>,
+}
+
+/// Handed to the work of a task to report progress and notice cancellation.
+pub struct TaskHandle {
+    shared: Arc<Shared>,
+    egui_ctx: Option<egui::Context>,
+}
+
+impl TaskHandle {
+    /// Set when the user cancels the task, the work should return soon after.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// From 0 to 1, `None` while the length of the work isn't known.
+    pub fn set_progress(&self, fraction: Option<f32>) {
+        *self.shared.progress.lock() = fraction.map(|fraction| fraction.clamp(0.0, 1.0));
+        self.request_repaint();
+    }
+
+    fn finish(&self, result: Result<String, String>) {
+        let outcome = match result {
+            _ if self.is_cancelled() => TaskOutcome::Cancelled,
+            Ok(result) => TaskOutcome::Done(result),
+            Err(error) => TaskOutcome::Failed(error),
+        };
+        *self.shared.outcome.lock() = Some(outcome);
+        self.request_repaint();
+    }
+
+    fn request_repaint(&self) {
+        if let Some(ctx) = &self.egui_ctx {
+            ctx.request_repaint();
+        }
+    }
+}
+
+pub struct Task {
+    pub id: TaskId,
+    pub name: String,
+    /// `egui` time the task was spawned at.
+    started_at: f64,
+    /// `egui` time of the first frame after the task finished.
+    finished_at: Option<f64>,
+    shared: Arc<Shared>,
+}
+
+impl Task {
+    pub fn progress(&self) -> Option<f32> {
+        *self.shared.progress.lock()
+    }
+
+    /// `None` while the task is running.
+    pub fn outcome(&self) -> Option<TaskOutcome> {
+        self.shared.outcome.lock().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.shared.outcome.lock().is_none()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Seconds since the task was spawned, or how long it ran.
+    pub fn elapsed(&self, now: f64) -> f64 {
+        self.finished_at.unwrap_or(now) - self.started_at
+    }
+}
+
+#[derive(Default)]
+pub struct Tasks {
+    /// Oldest first.
+    tasks: VecDeque<Task>,
+    next_id: u64,
+    /// `egui` time of the current frame.
+    now: f64,
+    /// To repaint when a task makes progress.
+    egui_ctx: Option<egui::Context>,
+}
+
+impl Tasks {
+    /// Run `work` in the background, see [`Context::spawn_task`].
+    pub fn spawn(
+        &mut self,
+        name: String,
+        work: impl FnOnce(&TaskHandle) -> Result<String, String> + Send + 'static,
+    ) -> TaskId {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        debug!("Spawning task {name}");
+        let shared = Arc::new(Shared::default());
+        let handle = TaskHandle {
+            shared: shared.clone(),
+            egui_ctx: self.egui_ctx.clone(),
+        };
+        if let Err(e) = run(&name, handle, work) {
+            error!("Failed to start task {name}: {e}");
+            *shared.outcome.lock() = Some(TaskOutcome::Failed(e));
+        }
+        self.tasks.push_back(Task {
+            id,
+            name,
+            started_at: self.now,
+            finished_at: None,
+            shared,
+        });
+        self.trim();
+        id
+    }
+
+    /// Oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Task> {
+        self.tasks.iter()
+    }
+
+    pub fn get(&self, id: TaskId) -> Option<&Task> {
+        self.tasks.iter().find(|task| task.id == id)
+    }
+
+    pub fn running(&self) -> usize {
+        self.tasks.iter().filter(|task| task.is_running()).count()
+    }
+
+    pub fn now(&self) -> f64 {
+        self.now
+    }
+
+    pub fn clear_finished(&mut self) {
+        self.tasks.retain(Task::is_running);
+    }
+
+    pub(crate) fn set_egui_ctx(&mut self, ctx: egui::Context) {
+        self.egui_ctx = Some(ctx);
+    }
+
+    /// Note the time, and when the tasks that finished since the last frame did.
+    pub(crate) fn begin_frame(&mut self, now: f64) {
+        self.now = now;
+        for task in &mut self.tasks {
+            if task.finished_at.is_some() {
+                continue;
+            }
+            match task.outcome() {
+                None => {}
+                Some(TaskOutcome::Failed(error)) => {
+                    warn!("Task {} failed: {error}", task.name);
+                    task.finished_at = Some(now);
+                }
+                Some(outcome) => {
+                    debug!("Task {} finished: {outcome:?}", task.name);
+                    task.finished_at = Some(now);
+                }
+            }
+        }
+    }
+
+    /// Forget the oldest finished tasks beyond [`HISTORY_LEN`].
+    fn trim(&mut self) {
+        let mut finished = self.tasks.len() - self.running();
+        while finished > HISTORY_LEN {
+            let Some(index) = self.tasks.iter().position(|task| !task.is_running()) else {
+                break;
+            };
+            self.tasks.remove(index);
+            finished -= 1;
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run(
+    name: &str,
+    handle: TaskHandle,
+    work: impl FnOnce(&TaskHandle) -> Result<String, String> + Send + 'static,
+) -> Result<(), String> {
+    std::thread::Builder::new()
+        .name(format!("task {name}"))
+        .spawn(move || {
+            // Not a crash, the task shows the panic.
+            let result = crate::crash::catch(|| work(&handle))
+                .unwrap_or_else(|report| Err(format!("panicked: {}", report.message)));
+            handle.finish(result);
+        })
+        .map(drop)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn run(
+    _name: &str,
+    handle: TaskHandle,
+    work: impl FnOnce(&TaskHandle) -> Result<String, String> + Send + 'static,
+) -> Result<(), String> {
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = work(&handle);
+        handle.finish(result);
+    });
+    Ok(())
+}
+
+/// Running and recent tasks.
+#[derive(Default, Serialize, Deserialize)]
+pub struct TasksWindow {}
+
+impl TasksWindow {
+    pub fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let mut tasks = cx.tasks();
+        ui.horizontal(|ui| {
+            ui.label(cx.tr_args("tasks-running", &[("count", &tasks.running().to_string())]));
+            if ui.button(cx.tr("tasks-clear-finished")).clicked() {
+                tasks.clear_finished();
+            }
+        });
+        ui.separator();
+
+        if tasks.iter().next().is_none() {
+            ui.weak(cx.tr("tasks-none"));
+            return;
+        }
+        let now = tasks.now();
+        egui::Grid::new("tasks")
+            .num_columns(4)
+            .striped(true)
+            .show(ui, |ui| {
+                for task in tasks.iter().rev() {
+                    ui.label(&task.name);
+                    match task.outcome() {
+                        None => {
+                            let progress = task.progress();
+                            ui.add(
+                                egui::ProgressBar::new(progress.unwrap_or_default())
+                                    .desired_width(120.0)
+                                    .show_percentage()
+                                    .animate(progress.is_none()),
+                            );
+                        }
+                        Some(TaskOutcome::Done(result)) => {
+                            ui.label(result);
+                        }
+                        Some(TaskOutcome::Failed(error)) => {
+                            ui.colored_label(ui.visuals().error_fg_color, error);
+                        }
+                        Some(TaskOutcome::Cancelled) => {
+                            ui.weak(cx.tr("tasks-cancelled"));
+                        }
+                    }
+                    let seconds = format!("{:.1}", task.elapsed(now));
+                    ui.label(cx.tr_args("tasks-elapsed", &[("seconds", &seconds)]));
+                    if task.is_running() {
+                        let cancel = egui::Button::new(cx.tr("tasks-cancel"));
+                        if ui.add_enabled(!task.is_cancelled(), cancel).clicked() {
+                            info!("Cancelling task {}", task.name);
+                            task.cancel();
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        // Keep the elapsed time ticking.
+        if tasks.running() > 0 {
+            ui.ctx().request_repaint_after_secs(0.1);
+        }
+    }
+}
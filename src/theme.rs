@@ -0,0 +1,845 @@
+//! User-defined themes on top of egui's light and dark visuals, edited in the Settings window and
+//! picked from the menu bar.
+//!
+//! Natively each theme is a RON file in the `themes` directory next to the saved state, on the web
+//! the themes are part of the state.
+
+use crate::prelude::*;
+use egui::{
+    Color32, CornerRadius, DragValue, Margin, Stroke, Style, TextStyle, ThemePreference, Vec2,
+    Visuals, WidgetInfo, WidgetType,
+};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    /// Start from egui's dark visuals, otherwise the light ones.
+    pub dark: bool,
+    pub text: Color32,
+    /// Background of panels.
+    pub panel: Color32,
+    /// Background of windows and menus.
+    pub window: Color32,
+    /// Background of text edits, scroll bars and the like.
+    pub extreme_bg: Color32,
+    /// Background of buttons and other widgets.
+    pub widget: Color32,
+    /// Borders and separators.
+    pub stroke: Color32,
+    /// Selection and highlighted widgets.
+    pub accent: Color32,
+    pub hyperlink: Color32,
+    pub warn: Color32,
+    pub error: Color32,
+    pub rounding: u8,
+    pub item_spacing: Vec2,
+    pub button_padding: Vec2,
+    pub window_margin: i8,
+    pub stroke_width: f32,
+    pub body_size: f32,
+    pub heading_size: f32,
+    pub monospace_size: f32,
+    pub small_size: f32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let style = Style {
+            visuals: Visuals::dark(),
+            ..Default::default()
+        };
+        Self::from_style("Custom", &style)
+    }
+}
+
+impl Theme {
+    /// Capture the parts of a style a theme can change.
+    pub fn from_style(name: &str, style: &Style) -> Self {
+        let visuals = &style.visuals;
+        let size = |text_style: TextStyle| {
+            style
+                .text_styles
+                .get(&text_style)
+                .map_or(14.0, |font| font.size)
+        };
+        Self {
+            name: name.to_owned(),
+            dark: visuals.dark_mode,
+            text: visuals.text_color(),
+            panel: visuals.panel_fill,
+            window: visuals.window_fill,
+            extreme_bg: visuals.extreme_bg_color,
+            widget: visuals.widgets.inactive.bg_fill,
+            stroke: visuals.widgets.noninteractive.bg_stroke.color,
+            accent: visuals.selection.bg_fill,
+            hyperlink: visuals.hyperlink_color,
+            warn: visuals.warn_fg_color,
+            error: visuals.error_fg_color,
+            rounding: visuals.widgets.inactive.corner_radius.nw,
+            item_spacing: style.spacing.item_spacing,
+            button_padding: style.spacing.button_padding,
+            window_margin: style.spacing.window_margin.left,
+            stroke_width: visuals.window_stroke.width,
+            body_size: size(TextStyle::Body),
+            heading_size: size(TextStyle::Heading),
+            monospace_size: size(TextStyle::Monospace),
+            small_size: size(TextStyle::Small),
+        }
+    }
+
+    /// Dark theme in the product colors.
+    pub fn branded() -> Self {
+        Self {
+            name: "Brand".to_owned(),
+            dark: true,
+            text: Color32::from_rgb(225, 230, 240),
+            panel: Color32::from_rgb(22, 27, 38),
+            window: Color32::from_rgb(28, 34, 48),
+            extreme_bg: Color32::from_rgb(14, 18, 26),
+            widget: Color32::from_rgb(40, 48, 66),
+            stroke: Color32::from_rgb(60, 72, 96),
+            accent: Color32::from_rgb(0, 150, 136),
+            hyperlink: Color32::from_rgb(64, 196, 255),
+            warn: Color32::from_rgb(255, 171, 64),
+            error: Color32::from_rgb(255, 82, 82),
+            rounding: 6,
+            item_spacing: Vec2::new(8.0, 4.0),
+            button_padding: Vec2::new(8.0, 3.0),
+            window_margin: 8,
+            stroke_width: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// White on black with thick borders and larger text, for control-room displays.
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High contrast".to_owned(),
+            dark: true,
+            text: Color32::WHITE,
+            panel: Color32::BLACK,
+            window: Color32::BLACK,
+            extreme_bg: Color32::BLACK,
+            widget: Color32::from_gray(30),
+            stroke: Color32::WHITE,
+            accent: Color32::from_rgb(255, 210, 0),
+            hyperlink: Color32::from_rgb(0, 230, 255),
+            warn: Color32::from_rgb(255, 210, 0),
+            error: Color32::from_rgb(255, 90, 90),
+            rounding: 0,
+            stroke_width: 2.0,
+            body_size: 16.0,
+            heading_size: 22.0,
+            monospace_size: 15.0,
+            small_size: 12.0,
+            ..Self::default()
+        }
+    }
+
+    pub fn egui_theme(&self) -> egui::Theme {
+        if self.dark {
+            egui::Theme::Dark
+        } else {
+            egui::Theme::Light
+        }
+    }
+
+    /// Style the app with the theme, on top of its current style.
+    pub fn apply(&self, ctx: &egui::Context) {
+        let theme = self.egui_theme();
+        ctx.set_style_of(theme, self.style(&ctx.style_of(theme)));
+        ctx.set_theme(theme);
+    }
+
+    /// `base` with the theme applied, what the theme doesn't cover is kept.
+    pub fn style(&self, base: &Style) -> Style {
+        let mut visuals = match self.egui_theme() {
+            egui::Theme::Dark => Visuals::dark(),
+            egui::Theme::Light => Visuals::light(),
+        };
+        let corner_radius = CornerRadius::same(self.rounding);
+        visuals.override_text_color = Some(self.text);
+        visuals.panel_fill = self.panel;
+        visuals.window_fill = self.window;
+        visuals.extreme_bg_color = self.extreme_bg;
+        visuals.selection.bg_fill = self.accent;
+        visuals.hyperlink_color = self.hyperlink;
+        visuals.warn_fg_color = self.warn;
+        visuals.error_fg_color = self.error;
+        visuals.window_corner_radius = corner_radius;
+        visuals.menu_corner_radius = corner_radius;
+        visuals.window_stroke = Stroke::new(self.stroke_width, self.stroke);
+        visuals.widgets.noninteractive.bg_stroke = Stroke::new(self.stroke_width, self.stroke);
+        visuals.widgets.inactive.bg_fill = self.widget;
+        visuals.widgets.inactive.weak_bg_fill = self.widget;
+        visuals.widgets.inactive.bg_stroke = Stroke::new(self.stroke_width, self.stroke);
+        visuals.widgets.hovered.bg_stroke.width = self.stroke_width.max(1.0);
+        visuals.widgets.active.bg_stroke.width = self.stroke_width.max(1.0);
+        visuals.widgets.hovered.bg_stroke.color = self.accent;
+        for widget in [
+            &mut visuals.widgets.noninteractive,
+            &mut visuals.widgets.inactive,
+            &mut visuals.widgets.hovered,
+            &mut visuals.widgets.active,
+            &mut visuals.widgets.open,
+        ] {
+            widget.corner_radius = corner_radius;
+        }
+
+        let mut style = base.clone();
+        style.visuals = visuals;
+        style.spacing.item_spacing = self.item_spacing;
+        style.spacing.button_padding = self.button_padding;
+        style.spacing.window_margin = Margin::same(self.window_margin);
+        for (text_style, font) in &mut style.text_styles {
+            font.size = match text_style {
+                TextStyle::Body | TextStyle::Button => self.body_size,
+                TextStyle::Heading => self.heading_size,
+                TextStyle::Monospace => self.monospace_size,
+                TextStyle::Small => self.small_size,
+                TextStyle::Name(_) => continue,
+            };
+        }
+        style
+    }
+
+    /// Controls for every field, returns whether something changed.
+    fn editor_ui(&mut self, ui: &mut Ui, cx: &Context) -> bool {
+        let before = self.clone();
+        egui::Grid::new("theme_editor")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(cx.tr("theme-base"));
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.dark, false, cx.tr("theme-light"));
+                    ui.selectable_value(&mut self.dark, true, cx.tr("theme-dark"));
+                });
+                ui.end_row();
+
+                for (key, color) in [
+                    ("theme-color-text", &mut self.text),
+                    ("theme-color-panel", &mut self.panel),
+                    ("theme-color-window", &mut self.window),
+                    ("theme-color-text-field", &mut self.extreme_bg),
+                    ("theme-color-widget", &mut self.widget),
+                    ("theme-color-stroke", &mut self.stroke),
+                    ("theme-color-accent", &mut self.accent),
+                    ("theme-color-hyperlink", &mut self.hyperlink),
+                    ("theme-color-warning", &mut self.warn),
+                    ("theme-color-error", &mut self.error),
+                ] {
+                    let label = ui.label(cx.tr(key));
+                    ui.color_edit_button_srgba(color).labelled_by(label.id);
+                    ui.end_row();
+                }
+
+                let label = ui.label(cx.tr("theme-rounding"));
+                ui.add(egui::Slider::new(&mut self.rounding, 0..=16))
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(cx.tr("theme-stroke-width"));
+                ui.add(egui::Slider::new(&mut self.stroke_width, 0.0..=4.0))
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                let label = ui.label(cx.tr("theme-item-spacing"));
+                vec2_ui(ui, &mut self.item_spacing, label.id);
+                ui.end_row();
+
+                let label = ui.label(cx.tr("theme-button-padding"));
+                vec2_ui(ui, &mut self.button_padding, label.id);
+                ui.end_row();
+
+                let label = ui.label(cx.tr("theme-window-margin"));
+                ui.add(DragValue::new(&mut self.window_margin).range(0..=32))
+                    .labelled_by(label.id);
+                ui.end_row();
+
+                for (key, size) in [
+                    ("theme-size-body", &mut self.body_size),
+                    ("theme-size-heading", &mut self.heading_size),
+                    ("theme-size-monospace", &mut self.monospace_size),
+                    ("theme-size-small", &mut self.small_size),
+                ] {
+                    let label = ui.label(cx.tr(key));
+                    ui.add(egui::Slider::new(size, 6.0..=40.0))
+                        .labelled_by(label.id);
+                    ui.end_row();
+                }
+            });
+        *self != before
+    }
+}
+
+fn vec2_ui(ui: &mut Ui, value: &mut Vec2, label: egui::Id) {
+    ui.horizontal(|ui| {
+        ui.add(DragValue::new(&mut value.x).range(0.0..=32.0).prefix("x: "))
+            .labelled_by(label);
+        ui.add(DragValue::new(&mut value.y).range(0.0..=32.0).prefix("y: "))
+            .labelled_by(label);
+    });
+}
+
+/// Some widgets drawn with the theme, whatever theme the rest of the app uses.
+fn preview_ui(ui: &mut Ui, cx: &Context, theme: &Theme) {
+    ui.scope(|ui| {
+        ui.set_style(theme.style(ui.style()));
+        egui::Frame::window(ui.style()).show(ui, |ui| {
+            let heading = ui.heading(cx.tr("theme-preview"));
+            ui.label(cx.tr("theme-preview-body"));
+            ui.horizontal(|ui| {
+                let _ = ui.button(cx.tr("theme-preview-button"));
+                let _ = ui.selectable_label(true, cx.tr("theme-preview-selected"));
+                let mut checked = true;
+                ui.checkbox(&mut checked, cx.tr("theme-preview-checkbox"));
+            });
+            let mut value = 0.5;
+            ui.add(egui::Slider::new(&mut value, 0.0..=1.0))
+                .labelled_by(heading.id);
+            let mut text = cx.tr("theme-color-text-field");
+            ui.text_edit_singleline(&mut text).labelled_by(heading.id);
+            ui.hyperlink_to(
+                cx.tr("theme-color-hyperlink"),
+                "https://github.com/emilk/egui",
+            );
+            ui.colored_label(ui.visuals().warn_fg_color, cx.tr("theme-color-warning"));
+            ui.colored_label(ui.visuals().error_fg_color, cx.tr("theme-color-error"));
+            ui.monospace(cx.tr("theme-preview-monospace"));
+            ui.small(cx.tr("theme-preview-small"));
+        });
+    });
+}
+
+/// The custom themes and which one is in use, kept with the Settings window.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    /// Name of the custom theme in use, `None` follows egui's light/dark preference.
+    pub selected: Option<String>,
+    /// Natively only read from the state, for state saved before the themes had their own files.
+    #[cfg_attr(not(target_arch = "wasm32"), serde(skip_serializing))]
+    pub themes: Vec<Theme>,
+    /// Themes changed since they were last written to their files.
+    #[serde(skip)]
+    dirty: bool,
+    /// Index of the theme shown in the editor.
+    #[serde(skip)]
+    editing: usize,
+    /// RON pasted to import a theme.
+    #[serde(skip)]
+    import: String,
+    /// Name typed for the theme at the index, applied once it is unique.
+    #[serde(skip)]
+    rename: Option<(usize, String)>,
+    #[serde(skip)]
+    file_picker: ThemeFilePicker,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            themes: vec![Theme::branded(), Theme::high_contrast()],
+            dirty: false,
+            editing: 0,
+            import: String::new(),
+            rename: None,
+            file_picker: Default::default(),
+        }
+    }
+}
+
+impl ThemeSettings {
+    /// Read the theme files, a file replaces the theme with the same name.
+    pub fn load(&mut self) {
+        // Custom themes only in the state have to go to files on the next save.
+        self.dirty = self.themes != Self::default().themes;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(dir) = themes_dir() else {
+                return;
+            };
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+                Err(e) => {
+                    error!("Failed to read {}: {e}", dir.display());
+                    return;
+                }
+            };
+            let paths = entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|extension| extension == "ron"));
+            for path in paths {
+                let theme = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|ron| ron::from_str::<Theme>(&ron).map_err(|e| e.to_string()));
+                match theme {
+                    Ok(theme) => match self.themes.iter_mut().find(|t| t.name == theme.name) {
+                        Some(existing) => *existing = theme,
+                        None => self.themes.push(theme),
+                    },
+                    Err(e) => error!("Failed to load the theme {}: {e}", path.display()),
+                }
+            }
+        }
+    }
+
+    /// Write the themes to their files if they changed.
+    pub fn save(&mut self) {
+        if !std::mem::take(&mut self.dirty) {
+            return;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(dir) = themes_dir() else {
+                return;
+            };
+            if let Err(e) = std::fs::create_dir_all(&dir) {
+                error!("Failed to create {}: {e}", dir.display());
+                return;
+            }
+            for theme in &self.themes {
+                let path = theme_path(&dir, &theme.name);
+                let result = ron::ser::to_string_pretty(theme, Default::default())
+                    .map_err(|e| e.to_string())
+                    .and_then(|ron| std::fs::write(&path, ron).map_err(|e| e.to_string()));
+                if let Err(e) = result {
+                    error!("Failed to save the theme {}: {e}", path.display());
+                }
+            }
+        }
+    }
+
+    pub fn selected_theme(&self) -> Option<&Theme> {
+        let selected = self.selected.as_ref()?;
+        self.themes.iter().find(|theme| &theme.name == selected)
+    }
+
+    /// Style the app with the selected theme, or egui's defaults.
+    pub fn apply(&self, ctx: &egui::Context) {
+        match self.selected_theme() {
+            Some(theme) => theme.apply(ctx),
+            None => {
+                // Undo what a theme changes, the rest of the style stays.
+                let defaults = Style::default();
+                for (theme, visuals) in [
+                    (egui::Theme::Dark, Visuals::dark()),
+                    (egui::Theme::Light, Visuals::light()),
+                ] {
+                    ctx.style_mut_of(theme, |style| {
+                        style.visuals = visuals;
+                        style.spacing.item_spacing = defaults.spacing.item_spacing;
+                        style.spacing.button_padding = defaults.spacing.button_padding;
+                        style.spacing.window_margin = defaults.spacing.window_margin;
+                        style.text_styles = defaults.text_styles.clone();
+                    });
+                }
+            }
+        }
+    }
+
+    fn select(&mut self, ctx: &egui::Context, name: Option<String>) {
+        self.selected = name;
+        self.apply(ctx);
+    }
+
+    /// Theme picker for the menu bar.
+    pub fn menu_ui(&mut self, ui: &mut Ui, cx: &Context) {
+        ui.menu_button(cx.tr("menu-theme"), |ui| {
+            let preference = ui.ctx().options(|options| options.theme_preference);
+            for (value, icon, key) in [
+                (ThemePreference::System, "💻", "theme-system"),
+                (ThemePreference::Light, "☀", "theme-light"),
+                (ThemePreference::Dark, "🌙", "theme-dark"),
+            ] {
+                let selected = self.selected.is_none() && preference == value;
+                let label = format!("{icon} {}", cx.tr(key));
+                if ui.selectable_label(selected, label).clicked() {
+                    self.select(ui.ctx(), None);
+                    ui.ctx().set_theme(value);
+                    ui.close_menu();
+                }
+            }
+            ui.separator();
+            let mut clicked = None;
+            for theme in &self.themes {
+                let selected = self.selected.as_ref() == Some(&theme.name);
+                if ui.selectable_label(selected, &theme.name).clicked() {
+                    clicked = Some(theme.name.clone());
+                }
+            }
+            if let Some(name) = clicked {
+                self.select(ui.ctx(), Some(name));
+                ui.close_menu();
+            }
+        });
+    }
+
+    /// The theme editor page of the Settings window.
+    pub fn editor_ui(&mut self, ui: &mut Ui, cx: &Context) {
+        ui.horizontal(|ui| {
+            let editing = self
+                .themes
+                .get(self.editing)
+                .map(|theme| theme.name.clone());
+            let editing_name = cx.tr("theme-editing");
+            egui::ComboBox::from_id_salt("theme_editing")
+                .selected_text(editing.unwrap_or_else(|| cx.tr("theme-none")))
+                .show_ui(ui, |ui| {
+                    for (index, theme) in self.themes.iter().enumerate() {
+                        ui.selectable_value(&mut self.editing, index, &theme.name);
+                    }
+                })
+                .response
+                .on_hover_text(&editing_name)
+                .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, true, &editing_name));
+            if ui
+                .button(cx.tr("theme-new"))
+                .on_hover_text(cx.tr("theme-new-hint"))
+                .clicked()
+            {
+                let name = self.unique_name(&cx.tr("theme-new-name"));
+                let theme = Theme::from_style(&name, &ui.ctx().style());
+                self.themes.push(theme);
+                self.editing = self.themes.len() - 1;
+                self.dirty = true;
+            }
+            if let Some(theme) = self.themes.get(self.editing) {
+                if ui.button(cx.tr("theme-duplicate")).clicked() {
+                    let mut copy = theme.clone();
+                    copy.name = self.unique_name(&theme.name);
+                    self.themes.push(copy);
+                    self.editing = self.themes.len() - 1;
+                    self.dirty = true;
+                }
+            }
+            if self.editing < self.themes.len() && ui.button(cx.tr("theme-delete")).clicked() {
+                let removed = self.themes.remove(self.editing);
+                remove_file(&removed.name);
+                self.rename = None;
+                self.editing = self.editing.saturating_sub(1);
+                if self.selected.as_ref() == Some(&removed.name) {
+                    self.select(ui.ctx(), None);
+                }
+            }
+        });
+
+        let editing = self
+            .themes
+            .get(self.editing)
+            .map(|theme| theme.name.clone());
+        if let Some(name) = editing {
+            ui.horizontal(|ui| {
+                if self.selected.as_ref() == Some(&name) {
+                    ui.label(cx.tr("theme-in-use"));
+                } else if ui.button(cx.tr("theme-use")).clicked() {
+                    self.select(ui.ctx(), Some(name));
+                }
+                self.rename_ui(ui, cx);
+                if ui.button(cx.tr("theme-export")).clicked() {
+                    let theme = &self.themes[self.editing];
+                    match ron::ser::to_string_pretty(theme, Default::default())
+                        .map_err(|e| e.to_string())
+                        .and_then(|ron| crate::storage::export(cx, "theme", "ron", &ron))
+                    {
+                        Ok(location) => cx.notify(
+                            NotifyLevel::Success,
+                            cx.tr_args(
+                                "theme-exported",
+                                &[("name", &theme.name), ("location", &location)],
+                            ),
+                        ),
+                        Err(e) => cx.notify(
+                            NotifyLevel::Error,
+                            cx.tr_args("theme-export-failed", &[("error", &e)]),
+                        ),
+                    }
+                }
+            });
+        }
+        if let Some(theme) = self.themes.get_mut(self.editing) {
+            let old_name = theme.name.clone();
+            ui.columns(2, |columns| {
+                let changed = theme.editor_ui(&mut columns[0], cx);
+                preview_ui(&mut columns[1], cx, theme);
+                if changed {
+                    let is_selected = self.selected.as_ref() == Some(&old_name);
+                    if is_selected {
+                        self.selected = Some(theme.name.clone());
+                        // Live preview on the whole app.
+                        theme.apply(columns[0].ctx());
+                    }
+                    self.dirty = true;
+                }
+            });
+        }
+
+        ui.separator();
+        let label = ui
+            .horizontal(|ui| {
+                let label = ui.label(cx.tr("theme-import-label"));
+                if ui.button(cx.tr("theme-import-file")).clicked() {
+                    self.file_picker.open(ui.ctx());
+                }
+                label
+            })
+            .inner;
+        ui.add(
+            egui::TextEdit::multiline(&mut self.import)
+                .code_editor()
+                .desired_rows(3)
+                .hint_text(cx.tr("theme-import-hint")),
+        )
+        .labelled_by(label.id);
+        if ui
+            .add_enabled(
+                !self.import.trim().is_empty(),
+                egui::Button::new(cx.tr("theme-import")),
+            )
+            .clicked()
+        {
+            let ron = std::mem::take(&mut self.import);
+            if !self.import_ron(&ron, cx) {
+                self.import = ron;
+            }
+        }
+        match self.file_picker.show(ui.ctx(), cx) {
+            Some(Ok(ron)) => {
+                self.import_ron(&ron, cx);
+            }
+            Some(Err(e)) => cx.notify(
+                NotifyLevel::Error,
+                cx.tr_args("theme-import-failed", &[("error", &e)]),
+            ),
+            None => {}
+        }
+    }
+
+    /// Name field of the theme being edited. Names identify themes, so they have to differ.
+    fn rename_ui(&mut self, ui: &mut Ui, cx: &Context) {
+        let editing = self.editing;
+        let current = self.themes[editing].name.clone();
+        if self
+            .rename
+            .as_ref()
+            .is_none_or(|(index, _)| *index != editing)
+        {
+            self.rename = Some((editing, current.clone()));
+        }
+        let Some((_, name)) = &mut self.rename else {
+            return;
+        };
+        let label = ui.label(cx.tr("theme-name"));
+        let response = ui
+            .add(egui::TextEdit::singleline(name).desired_width(120.0))
+            .labelled_by(label.id);
+        let name = name.trim().to_owned();
+        let problem = if name.is_empty() {
+            Some(cx.tr("theme-name-empty"))
+        } else if name != current && self.themes.iter().any(|theme| theme.name == name) {
+            Some(cx.tr("theme-name-taken"))
+        } else {
+            None
+        };
+        match problem {
+            Some(problem) => {
+                ui.colored_label(ui.visuals().error_fg_color, problem);
+            }
+            None if response.lost_focus() && name != current => {
+                if self.selected.as_ref() == Some(&current) {
+                    self.selected = Some(name.clone());
+                }
+                remove_file(&current);
+                self.themes[editing].name = name;
+                self.dirty = true;
+            }
+            None => {}
+        }
+    }
+
+    /// Add a theme exported before, returns whether it was one.
+    fn import_ron(&mut self, ron: &str, cx: &Context) -> bool {
+        match ron::from_str::<Theme>(ron) {
+            Ok(mut theme) => {
+                theme.name = self.unique_name(&theme.name);
+                cx.notify(
+                    NotifyLevel::Success,
+                    cx.tr_args("theme-imported", &[("name", &theme.name)]),
+                );
+                self.themes.push(theme);
+                self.editing = self.themes.len() - 1;
+                self.dirty = true;
+                true
+            }
+            Err(e) => {
+                cx.notify(
+                    NotifyLevel::Error,
+                    cx.tr_args("theme-not-a-theme", &[("error", &e.to_string())]),
+                );
+                false
+            }
+        }
+    }
+
+    /// `name`, or `name (2)` and so on if a theme is called that already.
+    fn unique_name(&self, name: &str) -> String {
+        let taken = |candidate: &str| self.themes.iter().any(|theme| theme.name == candidate);
+        if !taken(name) {
+            return name.to_owned();
+        }
+        (2..)
+            .map(|n| format!("{name} ({n})"))
+            .find(|candidate| !taken(candidate))
+            .unwrap_or_default()
+    }
+}
+
+/// Where the theme files go.
+#[cfg(not(target_arch = "wasm32"))]
+fn themes_dir() -> Option<std::path::PathBuf> {
+    eframe::storage_dir(crate::APP_NAME).map(|dir| dir.join("themes"))
+}
+
+/// File of the theme with the given name, characters that can't be in file names are replaced.
+#[cfg(not(target_arch = "wasm32"))]
+fn theme_path(dir: &std::path::Path, name: &str) -> std::path::PathBuf {
+    let file_name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '(' | ')') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dir.join(format!("{file_name}.ron"))
+}
+
+/// Delete the file of a theme that was deleted or renamed.
+fn remove_file(_name: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(dir) = themes_dir() {
+        let path = theme_path(&dir, _name);
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => error!("Failed to delete {}: {e}", path.display()),
+        }
+    }
+}
+
+/// Import → From file: a dialog to type the path natively, the browser's file picker on the web.
+#[derive(Default)]
+struct ThemeFilePicker {
+    /// Content of the path field while the dialog is open.
+    #[cfg(not(target_arch = "wasm32"))]
+    path: Option<String>,
+    /// Set once the browser read the picked file.
+    #[cfg(target_arch = "wasm32")]
+    picked: std::sync::Arc<egui::mutex::Mutex<Option<Result<String, String>>>>,
+}
+
+impl ThemeFilePicker {
+    fn open(&mut self, _ctx: &egui::Context) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.path = Some(String::new());
+        }
+        #[cfg(target_arch = "wasm32")]
+        if let Err(e) = web::pick(self.picked.clone(), _ctx.clone()) {
+            error!("Failed to show the file picker: {e:?}");
+        }
+    }
+
+    /// Show the dialog while it is open, returns the content of the file once one was picked.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn show(&mut self, ctx: &egui::Context, cx: &Context) -> Option<Result<String, String>> {
+        let path = self.path.as_mut()?;
+        let mut picked = None;
+        let mut cancel = false;
+        let modal = egui::Modal::new(egui::Id::new("import_theme")).show(ctx, |ui| {
+            ui.set_width(320.0);
+            ui.heading(cx.tr("theme-import-title"));
+            let response = ui.add(
+                egui::TextEdit::singleline(path)
+                    .hint_text(cx.tr("theme-import-path-hint"))
+                    .desired_width(f32::INFINITY),
+            );
+            let valid = !path.trim().is_empty();
+            let entered = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+            ui.add_space(8.0);
+            egui::Sides::new().show(
+                ui,
+                |_ui| {},
+                |ui| {
+                    let import = ui.add_enabled(valid, egui::Button::new(cx.tr("theme-import")));
+                    if import.clicked() || (valid && entered) {
+                        picked =
+                            Some(std::fs::read_to_string(path.trim()).map_err(|e| e.to_string()));
+                    }
+                    if ui.button(cx.tr("theme-cancel")).clicked() {
+                        cancel = true;
+                    }
+                },
+            );
+        });
+        if picked.is_some() || cancel || modal.should_close() {
+            self.path = None;
+        }
+        picked
+    }
+
+    /// Returns the content of the file once the browser read it.
+    #[cfg(target_arch = "wasm32")]
+    fn show(&mut self, _ctx: &egui::Context, _cx: &Context) -> Option<Result<String, String>> {
+        self.picked.lock().take()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use eframe::wasm_bindgen::closure::Closure;
+    use eframe::wasm_bindgen::{JsCast as _, JsValue};
+    use egui::mutex::Mutex;
+    use std::sync::Arc;
+
+    /// Let the user pick a theme file, its content ends up in `picked`.
+    pub(super) fn pick(
+        picked: Arc<Mutex<Option<Result<String, String>>>>,
+        ctx: egui::Context,
+    ) -> Result<(), JsValue> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or_else(|| JsValue::from_str("no document"))?;
+        let input: web_sys::HtmlInputElement = document.create_element("input")?.dyn_into()?;
+        input.set_type("file");
+        input.set_accept(".ron");
+        let target = input.clone();
+        let on_change = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+            let Some(file) = target.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            let picked = picked.clone();
+            let ctx = ctx.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let text = wasm_bindgen_futures::JsFuture::from(file.text())
+                    .await
+                    .map(|text| text.as_string().unwrap_or_default())
+                    .map_err(|e| format!("{e:?}"));
+                *picked.lock() = Some(text);
+                ctx.request_repaint();
+            });
+        });
+        input.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+        // There is no telling whether the user cancels, so it lives as long as the page.
+        on_change.forget();
+        input.click();
+        Ok(())
+    }
+}
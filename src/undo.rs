@@ -0,0 +1,164 @@
+//! Application-wide undo/redo, see [`Context::undo`].
+//!
+//! Every entry is a command holding snapshots of its target from before and after the change,
+//! serialized as RON:
+//! - tabs push edits of their own state with [`UndoHistory::push_tab`],
+//! - layout changes of the tile tree are recorded by the app.
+
+use crate::prelude::*;
+use egui_tiles::TileId;
+use std::collections::VecDeque;
+
+/// How many entries are kept by default.
+const DEFAULT_MAX_LEN: usize = 100;
+
+/// Edits of the same kind closer together than this are undone together by default, in seconds.
+pub const DEFAULT_GROUP_INTERVAL: f64 = 1.0;
+
+/// What an entry restores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UndoTarget {
+    /// The state of the tab in the pane.
+    Tab(TileId),
+    /// The tile tree with the tabs in it. Undoing keeps the current state of the tabs that are
+    /// still open, the saved one only comes back with a closed tab.
+    Layout,
+}
+
+pub struct UndoEntry {
+    /// Translation key of what was done, e.g. `action-close-tab`.
+    pub name: String,
+    pub target: UndoTarget,
+    pub before: String,
+    pub after: String,
+    /// `egui` time of the last change merged into this entry.
+    time: f64,
+}
+
+pub struct UndoHistory {
+    /// Oldest first.
+    done: VecDeque<UndoEntry>,
+    /// Most recently undone last.
+    undone: Vec<UndoEntry>,
+    pub max_len: usize,
+    /// Merge consecutive edits with the same name and target within this many seconds.
+    pub group_interval: Option<f64>,
+    /// The pane whose UI is being drawn, the target of [`UndoHistory::push_tab`].
+    current_pane: Option<TileId>,
+    /// Panes that pushed an entry since [`UndoHistory::take_edited_panes`] was last called.
+    edited_panes: Vec<TileId>,
+    now: f64,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        Self {
+            done: VecDeque::new(),
+            undone: Vec::new(),
+            max_len: DEFAULT_MAX_LEN,
+            group_interval: Some(DEFAULT_GROUP_INTERVAL),
+            current_pane: None,
+            edited_panes: Vec::new(),
+            now: 0.0,
+        }
+    }
+}
+
+impl UndoHistory {
+    /// Record an edit of the tab currently being drawn, call from [`TabUi::ui`].
+    ///
+    /// `before` and `after` are the whole state of the tab, e.g.
+    /// `cx.undo().push_tab("action-edit-text", &TabA { s: old_text }, self)`.
+    pub fn push_tab<T: Serialize>(&mut self, name: &str, before: &T, after: &T) {
+        let Some(tile_id) = self.current_pane else {
+            warn!("Undo entry {name:?} pushed outside of a tab, ignored");
+            return;
+        };
+        match (ron::to_string(before), ron::to_string(after)) {
+            (Ok(before), Ok(after)) => {
+                if !self.edited_panes.contains(&tile_id) {
+                    self.edited_panes.push(tile_id);
+                }
+                self.push(name, UndoTarget::Tab(tile_id), before, after);
+            }
+            (Err(e), _) | (_, Err(e)) => error!("Failed to record undo entry {name:?}: {e}"),
+        }
+    }
+
+    /// Record a change, merging it into the previous entry when grouped.
+    pub fn push(&mut self, name: &str, target: UndoTarget, before: String, after: String) {
+        self.undone.clear();
+        if let (Some(interval), Some(last)) = (self.group_interval, self.done.back_mut()) {
+            if last.name == name && last.target == target && self.now - last.time < interval {
+                last.after = after;
+                last.time = self.now;
+                return;
+            }
+        }
+        if before == after {
+            return;
+        }
+        self.done.push_back(UndoEntry {
+            name: name.to_owned(),
+            target,
+            before,
+            after,
+            time: self.now,
+        });
+        while self.done.len() > self.max_len {
+            self.done.pop_front();
+        }
+    }
+
+    /// Name of the entry [`UndoHistory::undo`] would return.
+    pub fn undo_name(&self) -> Option<&str> {
+        self.done.back().map(|entry| entry.name.as_str())
+    }
+
+    /// Name of the entry [`UndoHistory::redo`] would return.
+    pub fn redo_name(&self) -> Option<&str> {
+        self.undone.last().map(|entry| entry.name.as_str())
+    }
+
+    /// Take the last change, the caller restores [`UndoEntry::before`].
+    pub fn undo(&mut self) -> Option<&UndoEntry> {
+        let entry = self.done.pop_back()?;
+        self.undone.push(entry);
+        self.undone.last()
+    }
+
+    /// Take the last undone change, the caller restores [`UndoEntry::after`].
+    pub fn redo(&mut self) -> Option<&UndoEntry> {
+        let mut entry = self.undone.pop()?;
+        // Don't merge with whatever is done next.
+        entry.time = f64::NEG_INFINITY;
+        self.done.push_back(entry);
+        self.done.back()
+    }
+
+    pub fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+    }
+
+    pub(crate) fn begin_frame(&mut self, now: f64) {
+        self.now = now;
+    }
+
+    pub(crate) fn take_edited_panes(&mut self) -> Vec<TileId> {
+        std::mem::take(&mut self.edited_panes)
+    }
+
+    /// State of the tab after its most recent edit that wasn't undone.
+    pub(crate) fn tab_state(&self, tile_id: TileId) -> Option<&str> {
+        self.done
+            .iter()
+            .rev()
+            .find(|entry| entry.target == UndoTarget::Tab(tile_id))
+            .map(|entry| entry.after.as_str())
+    }
+
+    pub(crate) fn set_current_pane(&mut self, tile_id: Option<TileId>) {
+        self.current_pane = tile_id;
+    }
+}
@@ -1,15 +1,67 @@
+use crate::build_info;
 use crate::prelude::*;
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct AboutWindow {}
 
 impl AboutWindow {
-    pub fn ui(&mut self, ui: &mut Ui, _cx: &mut Context) {
+    pub fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let (renderer, git_hash, build_date) = {
+            let diagnostics = cx.diagnostics();
+            (
+                diagnostics.renderer.clone(),
+                diagnostics.git_hash.clone(),
+                diagnostics.build_date.clone(),
+            )
+        };
+
+        ui.heading(build_info::NAME);
+        egui::Grid::new("about_build")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label(cx.tr("about-version"));
+                ui.label(build_info::VERSION);
+                ui.end_row();
+
+                ui.label(cx.tr("about-commit"));
+                ui.monospace(git_hash);
+                ui.end_row();
+
+                ui.label(cx.tr("about-built"));
+                ui.label(build_date);
+                ui.end_row();
+
+                ui.label(cx.tr("about-target"));
+                ui.monospace(build_info::TARGET);
+                ui.end_row();
+
+                ui.label(cx.tr("about-features"));
+                ui.label(build_info::features().join(", "));
+                ui.end_row();
+
+                ui.label(cx.tr("about-renderer"));
+                match &renderer {
+                    Some(renderer) => ui.label(renderer),
+                    None => ui.weak(cx.tr("about-unknown")),
+                };
+                ui.end_row();
+            });
+
+        if ui
+            .button(cx.tr("about-copy-diagnostics"))
+            .on_hover_text(cx.tr("about-copy-diagnostics-hint"))
+            .clicked()
+        {
+            ui.ctx()
+                .copy_text(build_info::diagnostics(renderer.as_deref()));
+        }
+
+        ui.separator();
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
-            ui.label("Powered by ");
+            ui.label(format!("{} ", cx.tr("about-powered-by")));
             ui.hyperlink_to("egui", "https://github.com/emilk/egui");
-            ui.label(" and ");
+            ui.label(format!(" {} ", cx.tr("about-and")));
             ui.hyperlink_to(
                 "eframe",
                 "https://github.com/emilk/egui/tree/master/crates/eframe",
@@ -1,11 +1,211 @@
-use egui::Ui;
-use serde::{Deserialize, Serialize};
+use crate::diagnostics::Diagnostics;
+use crate::prelude::*;
+use crate::storage::StorageEntry;
+use egui::{pos2, vec2, Sense, Stroke, TextStyle};
 
 #[derive(Default, Serialize, Deserialize)]
-pub struct DebugWindow {}
+pub struct DebugWindow {
+    #[cfg(feature = "profiling")]
+    #[serde(skip)]
+    profiler: crate::profiler::ProfilerViewer,
+    /// Reading all of the storage is too slow for every frame, it is read again after a save.
+    #[serde(skip)]
+    storage: Option<StoredEntries>,
+}
+
+struct StoredEntries {
+    entries: Result<Vec<StorageEntry>, String>,
+    /// [`Diagnostics::saves`] when they were read.
+    saves: u64,
+}
+
+impl DebugWindow {
+    pub fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        let diagnostics = cx.diagnostics();
+        let saves = diagnostics.saves;
+
+        ui.heading(cx.tr("debug-frame-time"));
+        let mean = format!("{:.2}", 1e3 * diagnostics.mean_frame_time());
+        ui.label(cx.tr_args("debug-mean-cpu", &[("ms", &mean)]));
+        frame_time_graph(ui, &diagnostics);
+
+        ui.separator();
+        ui.collapsing(cx.tr("debug-repaint-causes"), |ui| {
+            if diagnostics.repaint_causes.is_empty() {
+                ui.weak(cx.tr("debug-none"));
+            }
+            for cause in &diagnostics.repaint_causes {
+                ui.monospace(cause.to_string());
+            }
+        });
+
+        ui.separator();
+        egui::Grid::new("debug_stats")
+            .num_columns(2)
+            .show(ui, |ui| {
+                let tiles = diagnostics.tiles;
+                ui.label(cx.tr("debug-tiles"));
+                ui.label(tiles.total().to_string());
+                ui.end_row();
+
+                ui.label(cx.tr("debug-panes"));
+                ui.label(tiles.panes.to_string());
+                ui.end_row();
+
+                ui.label(cx.tr("debug-containers"));
+                ui.label(tiles.containers.to_string());
+                ui.end_row();
+
+                let (textures, texture_bytes) = {
+                    let tex_manager = ui.ctx().tex_manager();
+                    let tex_manager = tex_manager.read();
+                    let bytes: usize = tex_manager
+                        .allocated()
+                        .map(|(_, meta)| meta.bytes_used())
+                        .sum();
+                    (tex_manager.num_allocated(), bytes)
+                };
+                ui.label(cx.tr("debug-textures"));
+                ui.label(format!("{textures} ({})", format_bytes(texture_bytes)));
+                ui.end_row();
+
+                ui.label(cx.tr("debug-last-save-size"));
+                match diagnostics.last_save_size {
+                    Some(size) => ui.label(format_bytes(size)),
+                    None => ui.weak(cx.tr("debug-not-saved")),
+                };
+                ui.end_row();
+
+                if let Some(e) = &diagnostics.last_save_error {
+                    ui.label(cx.tr("debug-last-save-error"));
+                    ui.colored_label(ui.visuals().error_fg_color, e);
+                    ui.end_row();
+                }
+            });
+        drop(diagnostics);
+
+        ui.separator();
+        ui.collapsing(cx.tr("debug-storage"), |ui| self.storage_ui(ui, cx, saves));
+
+        ui.separator();
+        ui.collapsing(cx.tr("debug-egui-memory"), |ui| {
+            ui.ctx().clone().memory_ui(ui);
+        });
+        ui.collapsing(cx.tr("debug-egui-textures"), |ui| {
+            ui.ctx().clone().texture_ui(ui);
+        });
+
+        #[cfg(feature = "profiling")]
+        ui.collapsing(cx.tr("debug-profiler"), |ui| {
+            self.profiler.ui(ui, cx);
+        });
+    }
+}
+
+fn frame_time_graph(ui: &mut Ui, diagnostics: &Diagnostics) {
+    let history = &diagnostics.frame_times;
+    let size = vec2(ui.available_size_before_wrap().x.max(200.0), 60.0);
+    let (rect, response) = ui.allocate_at_least(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    // Scale the graph so that a 60 fps frame budget is always visible.
+    let max_cpu_usage = history
+        .values()
+        .fold(1.0 / 60.0, |max: f32, value| max.max(value));
+    let now = ui.input(|i| i.time);
+    let stroke = Stroke::new(1.0, ui.visuals().text_color());
+    for (time, cpu_usage) in history.iter() {
+        let age = (now - time) as f32;
+        let x = rect.right() - rect.width() * age / history.max_age();
+        let y = rect.bottom() - rect.height() * cpu_usage / max_cpu_usage;
+        painter.line_segment([pos2(x, rect.bottom()), pos2(x, y)], stroke);
+    }
+
+    let budget_y = rect.bottom() - rect.height() / 60.0 / max_cpu_usage;
+    painter.line_segment(
+        [pos2(rect.left(), budget_y), pos2(rect.right(), budget_y)],
+        Stroke::new(1.0, ui.visuals().warn_fg_color),
+    );
+
+    if let Some(pointer) = response.hover_pos() {
+        let cpu_usage = (rect.bottom() - pointer.y) / rect.height() * max_cpu_usage;
+        painter.text(
+            pointer,
+            egui::Align2::LEFT_BOTTOM,
+            format!("{:.1} ms", 1e3 * cpu_usage),
+            TextStyle::Monospace.resolve(ui.style()),
+            ui.visuals().text_color(),
+        );
+        painter.line_segment(
+            [pos2(rect.left(), pointer.y), pos2(rect.right(), pointer.y)],
+            stroke,
+        );
+    }
+}
 
 impl DebugWindow {
-    pub fn ui(&mut self, ui: &mut Ui) {
-        ui.label("Debug");
+    fn storage_ui(&mut self, ui: &mut Ui, cx: &Context, saves: u64) {
+        ui.horizontal(|ui| {
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(path) = crate::storage::file_path() {
+                ui.label(cx.tr_args(
+                    "debug-storage-file",
+                    &[("path", &path.display().to_string())],
+                ));
+            }
+            #[cfg(target_arch = "wasm32")]
+            ui.label(cx.tr("debug-storage-browser"));
+
+            if ui.button(cx.tr("debug-storage-refresh")).clicked() {
+                self.storage = None;
+            }
+        });
+
+        if self
+            .storage
+            .as_ref()
+            .is_some_and(|stored| stored.saves != saves)
+        {
+            self.storage = None;
+        }
+        let stored = self.storage.get_or_insert_with(|| StoredEntries {
+            entries: crate::storage::entries(),
+            saves,
+        });
+        let entries = match &stored.entries {
+            Ok(entries) => entries,
+            Err(e) => {
+                ui.colored_label(ui.visuals().error_fg_color, e);
+                return;
+            }
+        };
+        if entries.is_empty() {
+            ui.weak(cx.tr("debug-storage-empty"));
+            return;
+        }
+        egui::Grid::new("debug_storage")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for entry in entries {
+                    ui.monospace(&entry.key);
+                    ui.label(format_bytes(entry.size));
+                    ui.end_row();
+                }
+                ui.strong(cx.tr("debug-storage-total"));
+                ui.strong(format_bytes(entries.iter().map(|entry| entry.size).sum()));
+                ui.end_row();
+            });
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
     }
 }
@@ -1,5 +1,8 @@
 use crate::context::Context;
-use egui::{Ui, Window};
+use crate::notifications::NotificationsWindow;
+use crate::prelude::{profile_function, profile_scope};
+use crate::tasks::TasksWindow;
+use egui::{Id, Ui, Window};
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -12,21 +15,46 @@ mod settings;
 pub enum WindowKind {
     #[strum(message = "About")]
     About(about::AboutWindow),
-    #[strum(message = "Settings", detailed_message = "Open configuration window")]
+    #[strum(message = "Settings")]
     Settings(settings::SettingsWindow),
     #[strum(message = "Log viewer")]
     LogViewer(log_viewer::LogViewer),
     #[strum(message = "Debug")]
     Debug(debug::DebugWindow),
+    #[strum(message = "Notifications")]
+    Notifications(NotificationsWindow),
+    #[strum(message = "Tasks")]
+    Tasks(TasksWindow),
 }
 
 impl WindowKind {
+    /// Translation key of the title, the strum message is the English title and the window id.
+    fn title_key(&self) -> &'static str {
+        match self {
+            WindowKind::About(_) => "window-about",
+            WindowKind::Settings(_) => "window-settings",
+            WindowKind::LogViewer(_) => "window-log-viewer",
+            WindowKind::Debug(_) => "window-debug",
+            WindowKind::Notifications(_) => "window-notifications",
+            WindowKind::Tasks(_) => "window-tasks",
+        }
+    }
+
+    fn hint_key(&self) -> Option<&'static str> {
+        match self {
+            WindowKind::Settings(_) => Some("window-settings-hint"),
+            _ => None,
+        }
+    }
+
     pub fn ui(&mut self, cx: &mut Context, ui: &mut Ui) {
         match self {
             WindowKind::About(about) => about.ui(ui, cx),
-            WindowKind::Settings(settings) => settings.ui(ui),
+            WindowKind::Settings(settings) => settings.ui(ui, cx),
             WindowKind::LogViewer(log_viewer) => log_viewer.ui(ui, cx),
-            WindowKind::Debug(debug) => debug.ui(ui),
+            WindowKind::Debug(debug) => debug.ui(ui, cx),
+            WindowKind::Notifications(notifications) => notifications.ui(ui, cx),
+            WindowKind::Tasks(tasks) => tasks.ui(ui, cx),
         }
     }
 }
@@ -51,23 +79,34 @@ pub enum WindowToggleButtonsLocations {
 }
 
 impl UniqueWindows {
-    pub fn toggle_buttons(&mut self, location: WindowToggleButtonsLocations, ui: &mut Ui) -> bool {
+    pub fn toggle_buttons(
+        &mut self,
+        location: WindowToggleButtonsLocations,
+        cx: &Context,
+        ui: &mut Ui,
+    ) -> bool {
         let mut clicked = false;
         let filter = |item: &&mut (WindowKind, bool)| match location {
             WindowToggleButtonsLocations::File => {
                 matches!(item.0, WindowKind::Settings(_))
             }
             WindowToggleButtonsLocations::Window => {
-                matches!(item.0, WindowKind::LogViewer(_)) || matches!(item.0, WindowKind::Debug(_))
+                matches!(
+                    item.0,
+                    WindowKind::LogViewer(_)
+                        | WindowKind::Debug(_)
+                        | WindowKind::Notifications(_)
+                        | WindowKind::Tasks(_)
+                )
             }
             WindowToggleButtonsLocations::Help => {
                 matches!(item.0, WindowKind::About(_))
             }
         };
         for (window, is_visible) in self.windows.iter_mut().filter(filter) {
-            let r = ui.toggle_value(is_visible, window.get_message().unwrap_or("W"));
-            let r = if let Some(detailed_message) = window.get_detailed_message() {
-                r.on_hover_text(detailed_message)
+            let r = ui.toggle_value(is_visible, cx.tr(window.title_key()));
+            let r = if let Some(hint_key) = window.hint_key() {
+                r.on_hover_text(cx.tr(hint_key))
             } else {
                 r
             };
@@ -78,12 +117,56 @@ impl UniqueWindows {
         clicked
     }
 
+    /// Add the windows that state saved by an older version doesn't have yet.
+    /// The saved windows keep their settings, and all of them end up in the order of [`WindowKind`].
+    pub fn add_missing(&mut self) {
+        let mut saved = std::mem::take(&mut self.windows);
+        self.windows = WindowKind::iter()
+            .map(|default| {
+                saved
+                    .iter()
+                    .position(|(window, _)| {
+                        std::mem::discriminant(window) == std::mem::discriminant(&default)
+                    })
+                    .map_or((default, false), |index| saved.swap_remove(index))
+            })
+            .collect();
+    }
+
+    pub fn settings_mut(&mut self) -> Option<&mut settings::SettingsWindow> {
+        self.windows
+            .iter_mut()
+            .find_map(|(window, _)| match window {
+                WindowKind::Settings(settings) => Some(settings),
+                _ => None,
+            })
+    }
+
+    pub fn is_open(&self, title: &str) -> bool {
+        self.windows
+            .iter()
+            .any(|(window, is_visible)| *is_visible && window.get_message() == Some(title))
+    }
+
+    /// Show the window with the given title (as shown in the menus).
+    pub fn open(&mut self, title: &str) {
+        for (window, is_visible) in &mut self.windows {
+            if window.get_message() == Some(title) {
+                *is_visible = true;
+            }
+        }
+    }
+
     pub fn show_open_windows(&mut self, cx: &mut Context, ctx: &egui::Context) {
+        profile_function!();
         for (window, is_visible) in &mut self.windows {
             if !*is_visible {
                 continue;
             }
-            Window::new(window.get_message().unwrap_or("W"))
+            let id = window.get_message().unwrap_or("W");
+            profile_scope!("window", id);
+            Window::new(cx.tr(window.title_key()))
+                .id(Id::new(id))
                 .open(is_visible)
                 .collapsible(true)
                 .scroll([true, true])
@@ -1,11 +1,76 @@
-use egui::Ui;
-use serde::{Deserialize, Serialize};
+use crate::fonts::FontSettings;
+use crate::prelude::*;
+use crate::theme::ThemeSettings;
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Default, Clone, Copy, PartialEq, Serialize, Deserialize, EnumIter)]
+enum SettingsPage {
+    #[default]
+    General,
+    Theme,
+    Fonts,
+}
+
+impl SettingsPage {
+    fn title_key(self) -> &'static str {
+        match self {
+            SettingsPage::General => "settings-general",
+            SettingsPage::Theme => "settings-theme",
+            SettingsPage::Fonts => "settings-fonts",
+        }
+    }
+}
 
 #[derive(Default, Serialize, Deserialize)]
-pub struct SettingsWindow {}
+pub struct SettingsWindow {
+    #[serde(default)]
+    page: SettingsPage,
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub theme: ThemeSettings,
+    #[serde(default)]
+    pub fonts: FontSettings,
+}
 
 impl SettingsWindow {
-    pub fn ui(&mut self, ui: &mut Ui) {
-        ui.label("Settings:");
+    pub fn ui(&mut self, ui: &mut Ui, cx: &mut Context) {
+        ui.horizontal(|ui| {
+            for page in SettingsPage::iter() {
+                ui.selectable_value(&mut self.page, page, cx.tr(page.title_key()));
+            }
+        });
+        ui.separator();
+        match self.page {
+            SettingsPage::General => self.general_ui(ui, cx),
+            SettingsPage::Theme => self.theme.editor_ui(ui, cx),
+            SettingsPage::Fonts => self.fonts.ui(ui, cx),
+        }
+    }
+
+    fn general_ui(&mut self, ui: &mut Ui, cx: &Context) {
+        ui.horizontal(|ui| {
+            let label = ui.label(cx.tr("settings-language"));
+            let before = self.language;
+            egui::ComboBox::from_id_salt("language")
+                .selected_text(self.language.native_name())
+                .show_ui(ui, |ui| {
+                    for language in Language::iter() {
+                        ui.selectable_value(&mut self.language, language, language.native_name());
+                    }
+                })
+                .response
+                .labelled_by(label.id);
+            if self.language != before {
+                cx.i18n().set_language(self.language);
+            }
+        });
+    }
+
+    /// Apply the persisted settings that live in the egui context.
+    pub fn attach(&mut self, ctx: &egui::Context) {
+        self.theme.load();
+        self.theme.apply(ctx);
+        self.fonts.apply(ctx);
     }
 }
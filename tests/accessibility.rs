@@ -0,0 +1,114 @@
+//! What screen readers and keyboard users get from the app.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use egui::accesskit::Role;
+use egui_kittest::kittest::Queryable as _;
+
+/// Roles of the widgets the user can interact with.
+const INTERACTIVE_ROLES: &[Role] = &[
+    Role::Button,
+    Role::CheckBox,
+    Role::ColorWell,
+    Role::ComboBox,
+    Role::Link,
+    Role::MultilineTextInput,
+    Role::RadioButton,
+    Role::Slider,
+    Role::SpinButton,
+    Role::TextInput,
+];
+
+/// (menu, window title), Settings last so that its pages can be switched.
+const WINDOWS: [(&str, &str); 6] = [
+    ("Window", "Log viewer"),
+    ("Window", "Debug"),
+    ("Window", "Notifications"),
+    ("Window", "Tasks"),
+    ("Help", "About"),
+    ("File", "Settings"),
+];
+
+#[test]
+fn every_interactive_widget_has_a_name() {
+    let mut harness = harness(&MemoryStorage::default());
+    let mut unnamed = unnamed_widgets(&harness);
+
+    for (menu, title) in WINDOWS {
+        harness.get_by_label(menu).click();
+        harness.run();
+        harness.get_by_label(title).click();
+        harness.run();
+        assert!(harness.state().is_window_open(title));
+    }
+    unnamed.extend(unnamed_widgets(&harness));
+
+    for page in ["Theme", "Fonts"] {
+        // The page tabs are selectable, unlike the Theme menu.
+        harness
+            .get_by(|node| node.label().as_deref() == Some(page) && node.toggled().is_some())
+            .click();
+        harness.run();
+        if page == "Theme" {
+            // The editor only shows up with a theme to edit.
+            harness.get_by_label("New").click();
+            harness.run();
+        }
+        unnamed.extend(unnamed_widgets(&harness));
+    }
+
+    unnamed.sort();
+    unnamed.dedup();
+    assert!(
+        unnamed.is_empty(),
+        "widgets without a name:\n{}",
+        unnamed.join("\n")
+    );
+}
+
+/// The interactive widgets currently shown that a screen reader can't name.
+fn unnamed_widgets(
+    harness: &egui_kittest::Harness<'_, eframe_template::TemplateApp>,
+) -> Vec<String> {
+    harness
+        .query_all_by(|node| INTERACTIVE_ROLES.contains(&node.role()))
+        .filter(|node| {
+            // Icons like "*" or "➕" don't tell a screen reader user anything.
+            !node
+                .label()
+                .is_some_and(|label| label.chars().any(char::is_alphanumeric))
+        })
+        .map(|node| format!("{:?} {:?} {:?}", node.role(), node.label(), node.value()))
+        .collect()
+}
+
+#[test]
+fn f6_moves_the_focus_between_panes() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    press(&mut harness, egui::Modifiers::NONE);
+    let first = harness.state().focused_pane();
+    assert!(first.is_some());
+
+    press(&mut harness, egui::Modifiers::NONE);
+    let second = harness.state().focused_pane();
+    assert_ne!(second, first);
+
+    press(&mut harness, egui::Modifiers::SHIFT);
+    assert_eq!(harness.state().focused_pane(), first);
+}
+
+fn press(
+    harness: &mut egui_kittest::Harness<'_, eframe_template::TemplateApp>,
+    modifiers: egui::Modifiers,
+) {
+    harness.input_mut().events.push(egui::Event::Key {
+        key: egui::Key::F6,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    });
+    harness.run();
+}
@@ -0,0 +1,123 @@
+//! Behavioral tests driving [`TemplateApp`] headlessly through `egui_kittest`.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn default_layout() {
+    let harness = harness(&MemoryStorage::default());
+    let app = harness.state();
+    assert_eq!(app.pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+    assert!(app.is_side_panel_expanded());
+    assert!(!app.is_window_open("Settings"));
+}
+
+#[test]
+fn open_settings_from_file_menu() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("File").click();
+    harness.run();
+    harness.get_by_label("Settings").click();
+    harness.run();
+
+    assert!(harness.state().is_window_open("Settings"));
+}
+
+#[test]
+fn toggle_side_panel() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("Show/Hide side panel").click();
+    harness.run();
+
+    assert!(!harness.state().is_side_panel_expanded());
+}
+
+#[test]
+fn add_tab_with_plus_button() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("Add tab").click();
+    harness.run();
+    harness.get_by_label("New Tab B").click();
+    harness.run();
+
+    assert_eq!(
+        harness.state().pane_titles(),
+        ["Tab A", "Tab A", "Tab B", "Tab B"]
+    );
+}
+
+#[test]
+fn state_is_restored_from_storage() {
+    let mut harness = harness(&MemoryStorage::default());
+    harness.get_by_label("Show/Hide side panel").click();
+    harness.run();
+    let storage = common::save(&mut harness);
+
+    let harness = common::harness(&storage);
+    assert!(!harness.state().is_side_panel_expanded());
+}
+
+#[test]
+fn about_shows_build_info() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("Help").click();
+    harness.run();
+    harness.get_by_label("About").click();
+    harness.run();
+
+    harness.get_by_label(env!("CARGO_PKG_VERSION"));
+    harness.get_by_label("Copy diagnostics");
+}
+
+#[test]
+fn undo_and_redo_adding_a_tab() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("Add tab").click();
+    harness.run();
+    harness.get_by_label("New Tab B").click();
+    harness.run();
+
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label("Undo Add tab").click();
+    harness.run();
+    assert_eq!(harness.state().pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label("Redo Add tab").click();
+    harness.run();
+    assert_eq!(
+        harness.state().pane_titles(),
+        ["Tab A", "Tab A", "Tab B", "Tab B"]
+    );
+}
+
+#[test]
+fn select_theme_from_menu_bar() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("Theme").click();
+    harness.run();
+    harness.get_by_label("High contrast").click();
+    harness.run();
+
+    assert_eq!(harness.ctx.style().visuals.panel_fill, egui::Color32::BLACK);
+}
+
+#[test]
+fn log_counts_in_status_bar_open_log_viewer() {
+    let mut harness = harness(&MemoryStorage::default());
+
+    harness.get_by_label("0 warnings, 0 errors").click();
+    harness.run();
+
+    assert!(harness.state().is_window_open("Log viewer"));
+}
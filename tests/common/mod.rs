@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+
+use eframe_template::TemplateApp;
+use egui_kittest::Harness;
+use std::collections::HashMap;
+
+/// [`eframe::Storage`] that keeps everything in memory, so tests don't touch the disk.
+#[derive(Default, Clone)]
+pub struct MemoryStorage {
+    pub values: HashMap<String, String>,
+}
+
+impl eframe::Storage for MemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.values.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.values.insert(key.to_owned(), value);
+    }
+
+    fn flush(&mut self) {}
+}
+
+/// Create the app from the given storage and run it until it settles.
+pub fn harness(storage: &MemoryStorage) -> Harness<'static, TemplateApp> {
+    harness_with_context(storage, eframe_template::context::Context::new())
+}
+
+/// Same as [`harness`], with a context prepared by the test.
+pub fn harness_with_context(
+    storage: &MemoryStorage,
+    cx: eframe_template::context::Context,
+) -> Harness<'static, TemplateApp> {
+    let storage = storage.clone();
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(1024.0, 768.0))
+        .with_max_steps(64)
+        .build_eframe(move |_cc| {
+            TemplateApp::with_storage(
+                Some(&storage),
+                cx.clone(),
+                Default::default(),
+                egui_tracing::EventCollector::default(),
+            )
+        });
+    harness.run();
+    harness
+}
+
+/// Save the app state the same way eframe does on shutdown.
+pub fn save(harness: &mut Harness<'_, TemplateApp>) -> MemoryStorage {
+    let mut storage = MemoryStorage::default();
+    eframe::App::save(harness.state_mut(), &mut storage);
+    storage
+}
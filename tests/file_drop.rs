@@ -0,0 +1,74 @@
+//! Files dropped on the window open in the tab kind that declared them.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use egui_kittest::kittest::Queryable as _;
+use std::sync::Arc;
+
+fn drop_file(harness: &mut egui_kittest::Harness<'_, eframe_template::TemplateApp>, name: &str) {
+    harness.input_mut().dropped_files.push(egui::DroppedFile {
+        name: name.to_owned(),
+        bytes: Some(Arc::from(&b"dropped text"[..])),
+        ..Default::default()
+    });
+    harness.run();
+}
+
+#[test]
+fn text_file_opens_in_a_new_tab() {
+    let mut harness = harness(&MemoryStorage::default());
+    drop_file(&mut harness, "notes.txt");
+
+    assert_eq!(
+        harness.state().pane_titles(),
+        ["Tab A", "Tab A", "Tab B", "notes.txt"]
+    );
+    assert_eq!(
+        harness.state().focused_pane().map(|(title, _)| title),
+        Some("notes.txt".to_owned())
+    );
+}
+
+#[test]
+fn file_dropped_near_a_side_splits_the_pane() {
+    let mut harness = harness(&MemoryStorage::default());
+    // Close to the right edge of the active pane.
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(1010.0, 400.0)));
+    harness.run();
+    drop_file(&mut harness, "notes.txt");
+
+    assert_eq!(
+        harness.state().pane_container("notes.txt"),
+        Some(egui_tiles::ContainerKind::Horizontal)
+    );
+    assert_eq!(
+        harness.state().pane_container("Tab B"),
+        Some(egui_tiles::ContainerKind::Tabs)
+    );
+}
+
+#[test]
+fn unsupported_file_is_reported() {
+    let mut harness = harness(&MemoryStorage::default());
+    drop_file(&mut harness, "data.bin");
+
+    assert_eq!(harness.state().pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+    harness.get_by_label("No tab opens data.bin");
+}
+
+#[test]
+fn opening_a_file_can_be_undone() {
+    let mut harness = harness(&MemoryStorage::default());
+    drop_file(&mut harness, "notes.md");
+
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label("Undo Open file").click();
+    harness.run();
+
+    assert_eq!(harness.state().pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+}
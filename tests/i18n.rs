@@ -0,0 +1,200 @@
+//! Checks the translations in `src/i18n` against each other and against the code.
+
+use eframe_template::i18n::{Bundle, Language};
+use std::collections::BTreeSet;
+use std::path::Path;
+use strum::IntoEnumIterator as _;
+
+#[test]
+fn every_key_is_translated() {
+    let english = Language::English.bundle();
+    let mut problems = Vec::new();
+    for language in Language::iter() {
+        let bundle = language.bundle();
+        for key in english.keys() {
+            if bundle.get(key).is_none() {
+                problems.push(format!("{}: untranslated key {key}", language.code()));
+            }
+        }
+        for key in bundle.keys() {
+            if english.get(key).is_none() {
+                problems.push(format!("{}: key {key} is not in English", language.code()));
+            }
+        }
+    }
+    assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
+
+/// String literals that look like keys, `"menu-file"`, must be in the English bundle.
+#[test]
+fn keys_used_in_the_code_exist() {
+    let english = Language::English.bundle();
+    let prefixes: BTreeSet<&str> = english
+        .keys()
+        .filter_map(|key| key.split_once('-').map(|(prefix, _)| prefix))
+        .collect();
+
+    let mut missing = Vec::new();
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for (path, key) in key_literals(&src) {
+        let known_prefix = key
+            .split_once('-')
+            .is_some_and(|(prefix, _)| prefixes.contains(prefix));
+        if known_prefix && english.get(&key).is_none() {
+            missing.push(format!("{}: {key}", path.display()));
+        }
+    }
+    assert!(missing.is_empty(), "missing keys:\n{}", missing.join("\n"));
+}
+
+/// Text shown by widgets goes through `cx.tr`, only icons and numbers can be written inline.
+#[test]
+fn widget_text_is_translated() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let untranslated: Vec<String> = widget_literals(&src)
+        .into_iter()
+        .filter(|(_, _, text)| has_words(text))
+        .map(|(path, line, text)| format!("{}:{line}: {text:?}", path.display()))
+        .collect();
+    assert!(
+        untranslated.is_empty(),
+        "untranslated text:\n{}",
+        untranslated.join("\n")
+    );
+}
+
+#[test]
+fn placeables_are_kept() {
+    let bundle = Bundle::parse("greeting = Hello { $name }!\nmultiline =\n    first\n    second\n");
+    assert_eq!(bundle.get("greeting"), Some("Hello { $name }!"));
+    assert_eq!(bundle.get("multiline"), Some("first\nsecond"));
+}
+
+/// `"lowercase-with-dashes"` literals in all `.rs` files under `dir`.
+fn key_literals(dir: &Path) -> Vec<(std::path::PathBuf, String)> {
+    let mut literals = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            literals.extend(key_literals(&path));
+            continue;
+        }
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path).unwrap();
+        for (start, _) in source.match_indices('"') {
+            let rest = &source[start + 1..];
+            let len = rest
+                .find(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'))
+                .unwrap_or(rest.len());
+            if rest[len..].starts_with('"') && rest[..len].contains('-') {
+                literals.push((path.clone(), rest[..len].to_owned()));
+            }
+        }
+    }
+    literals
+}
+
+/// Methods taking the text as first argument, and those taking it as second one.
+const TEXT_FIRST: &[&str] = &[
+    "button",
+    "heading",
+    "hint_text",
+    "label",
+    "menu_button",
+    "monospace",
+    "on_hover_text",
+    "small_button",
+    "strong",
+    "text",
+    "weak",
+];
+const TEXT_SECOND: &[&str] = &[
+    "checkbox",
+    "colored_label",
+    "radio_value",
+    "selectable_label",
+    "toggle_value",
+];
+
+/// String literals passed as text to widgets, directly or through `format!`, in all `.rs` files
+/// under `dir`, with their line number.
+fn widget_literals(dir: &Path) -> Vec<(std::path::PathBuf, usize, String)> {
+    let mut literals = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            literals.extend(widget_literals(&path));
+            continue;
+        }
+        if path.extension().is_none_or(|extension| extension != "rs") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path).unwrap();
+        // Drop comments, they may show how widgets were used before.
+        let code: String = source
+            .lines()
+            .map(|line| {
+                if line.trim_start().starts_with("//") {
+                    ""
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        for (method, text_second) in TEXT_FIRST
+            .iter()
+            .map(|method| (method, false))
+            .chain(TEXT_SECOND.iter().map(|method| (method, true)))
+        {
+            for (start, call) in code.match_indices(&format!(".{method}(")) {
+                let mut args = &code[start + call.len()..];
+                if text_second {
+                    let Some(comma) = first_argument_end(args) else {
+                        continue;
+                    };
+                    args = &args[comma + 1..];
+                }
+                let args = args.trim_start();
+                let args = args.strip_prefix("format!(").unwrap_or(args).trim_start();
+                if let Some((text, _)) =
+                    args.strip_prefix('"').and_then(|rest| rest.split_once('"'))
+                {
+                    let line = code[..start].lines().count();
+                    literals.push((path.clone(), line, text.to_owned()));
+                }
+            }
+        }
+    }
+    literals
+}
+
+/// Position of the comma ending the first argument.
+fn first_argument_end(args: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in args.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => return None,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether `text` has letters outside of `{}` placeholders.
+fn has_words(text: &str) -> bool {
+    let mut depth = 0;
+    text.chars().any(|c| {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        depth == 0 && c.is_alphabetic()
+    })
+}
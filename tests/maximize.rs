@@ -0,0 +1,74 @@
+//! Maximized pane and distraction-free mode, which leave the tile tree as it is.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use egui_kittest::kittest::Queryable as _;
+
+fn press(
+    harness: &mut egui_kittest::Harness<'_, eframe_template::TemplateApp>,
+    key: egui::Key,
+    modifiers: egui::Modifiers,
+) {
+    harness.input_mut().events.push(egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    });
+    harness.run();
+}
+
+#[test]
+fn maximize_from_window_menu_and_restore() {
+    let mut harness = harness(&MemoryStorage::default());
+    let before = common::save(&mut harness);
+
+    harness.get_by_label("Window").click();
+    harness.run();
+    harness.get_by_label("Maximize pane").click();
+    harness.run();
+    assert!(harness.state().maximized_pane().is_some());
+    assert!(!harness.state().is_distraction_free());
+
+    harness.get_by_label("Restore tiles").click();
+    harness.run();
+    assert_eq!(harness.state().maximized_pane(), None);
+    assert_eq!(common::save(&mut harness).values, before.values);
+}
+
+#[test]
+fn distraction_free_hides_the_top_bar_and_side_panel() {
+    let mut harness = harness(&MemoryStorage::default());
+    let shortcut = egui::Modifiers::COMMAND | egui::Modifiers::SHIFT;
+
+    press(&mut harness, egui::Key::F, shortcut);
+    assert!(harness.state().is_distraction_free());
+    assert!(harness.query_by_label("File").is_none());
+    assert!(harness
+        .query_by_label("Choose side panel sections")
+        .is_none());
+    // The side panel comes back as it was.
+    assert!(harness.state().is_side_panel_expanded());
+
+    press(&mut harness, egui::Key::Escape, egui::Modifiers::NONE);
+    assert!(!harness.state().is_distraction_free());
+    assert_eq!(harness.state().maximized_pane(), None);
+    harness.get_by_label("File");
+}
+
+#[test]
+fn maximized_pane_is_the_focused_one() {
+    let mut harness = harness(&MemoryStorage::default());
+    harness.get_by_label("Tab B").click();
+    harness.run();
+
+    press(
+        &mut harness,
+        egui::Key::M,
+        egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+    );
+    assert_eq!(harness.state().maximized_pane(), Some("Tab B".to_owned()));
+    assert_eq!(harness.state().pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+}
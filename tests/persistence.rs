@@ -0,0 +1,144 @@
+//! Saved state must keep loading across versions.
+//!
+//! `tests/fixtures/state` holds `State` saved by previous versions of the app.
+//! Before changing anything that is persisted, run
+//! `cargo test --test persistence -- --ignored save_fixture` and commit the new file.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use egui_kittest::kittest::Queryable as _;
+use std::path::Path;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/state");
+
+fn saved_state(storage: &MemoryStorage) -> &str {
+    storage
+        .values
+        .get(eframe::APP_KEY)
+        .expect("app state is saved")
+}
+
+#[test]
+fn round_trip() {
+    let mut harness = harness(&MemoryStorage::default());
+    harness.get_by_label("Show/Hide side panel").click();
+    harness.run();
+    harness.get_by_label("Add tab").click();
+    harness.run();
+    harness.get_by_label("New Tab B").click();
+    harness.run();
+    let storage = common::save(&mut harness);
+    eframe_template::check_saved_state(saved_state(&storage)).unwrap();
+
+    let mut restored = common::harness(&storage);
+    assert_eq!(
+        restored.state().pane_titles(),
+        harness.state().pane_titles()
+    );
+    assert!(!restored.state().is_side_panel_expanded());
+
+    // Saving again must not lose anything either.
+    let storage = common::save(&mut restored);
+    eframe_template::check_saved_state(saved_state(&storage)).unwrap();
+    let restored = common::harness(&storage);
+    assert_eq!(
+        restored.state().pane_titles(),
+        harness.state().pane_titles()
+    );
+}
+
+#[test]
+fn fixtures_load() {
+    let entries = std::fs::read_dir(FIXTURES).expect("fixtures directory exists");
+    let mut loaded = 0;
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|ext| ext != "ron") {
+            continue;
+        }
+        let saved = std::fs::read_to_string(&path).unwrap();
+        if let Err(e) = eframe_template::check_saved_state(&saved) {
+            panic!("{} failed to load: {e}", path.display());
+        }
+
+        // And through the app, the same way eframe restores it on start.
+        let mut storage = MemoryStorage::default();
+        storage.values.insert(eframe::APP_KEY.to_owned(), saved);
+        let harness = harness(&storage);
+        assert!(
+            !harness.state().pane_titles().is_empty(),
+            "{} restored without tabs",
+            path.display()
+        );
+        loaded += 1;
+    }
+    assert!(loaded > 0, "no fixtures in {FIXTURES}");
+}
+
+#[test]
+fn windows_added_since_keep_the_saved_settings() {
+    // The first commit had no Notifications and Tasks windows.
+    let saved = std::fs::read_to_string(Path::new(FIXTURES).join("v0.1.0-213a6ad.ron"))
+        .unwrap()
+        .replace("(Settings(()),false)", "(Settings((language:German)),true)");
+    let mut storage = MemoryStorage::default();
+    storage.values.insert(eframe::APP_KEY.to_owned(), saved);
+    let mut harness = harness(&storage);
+
+    assert!(harness.state().is_window_open("Settings"));
+    harness.get_by_label("Datei");
+
+    harness.get_by_label("Fenster").click();
+    harness.run();
+    harness.get_by_label("Aufgaben").click();
+    harness.run();
+    assert!(harness.state().is_window_open("Tasks"));
+}
+
+#[test]
+fn garbage_falls_back_to_default() {
+    for saved in ["", "(", "()", "not ron at all", "(tabs: 5)"] {
+        assert!(eframe_template::check_saved_state(saved).is_err());
+
+        let mut storage = MemoryStorage::default();
+        storage
+            .values
+            .insert(eframe::APP_KEY.to_owned(), saved.to_owned());
+        let harness = harness(&storage);
+        assert_eq!(harness.state().pane_titles(), ["Tab A", "Tab A", "Tab B"]);
+    }
+}
+
+#[test]
+fn unreadable_state_is_kept_until_discarded() {
+    let mut storage = MemoryStorage::default();
+    storage
+        .values
+        .insert(eframe::APP_KEY.to_owned(), "not ron at all".to_owned());
+    let mut harness = harness(&storage);
+
+    let saved = common::save(&mut harness);
+    assert!(!saved.values.contains_key(eframe::APP_KEY));
+    // Only the state that failed to load is held back.
+    let recent_projects = format!("{}/recent_projects", eframe::APP_KEY);
+    assert!(saved.values.contains_key(&recent_projects));
+
+    harness.get_by_label("Discard").click();
+    harness.run();
+    let saved = common::save(&mut harness);
+    eframe_template::check_saved_state(saved_state(&saved)).unwrap();
+}
+
+/// Write the state saved by the current version to the fixtures directory.
+#[test]
+#[ignore = "writes a new fixture, run manually before changing persisted types"]
+fn save_fixture() {
+    let mut harness = harness(&MemoryStorage::default());
+    let storage = common::save(&mut harness);
+    // Named after the build that wrote it, the version alone doesn't change with the format.
+    let name = format!("v{}-{}.ron", env!("CARGO_PKG_VERSION"), env!("GIT_HASH"));
+    let path = Path::new(FIXTURES).join(name);
+    std::fs::write(&path, saved_state(&storage)).unwrap();
+    println!("Saved {}", path.display());
+}
@@ -0,0 +1,84 @@
+//! Projects: the recent ones and the layouts saved next to them.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use eframe_template::project::{Project, RecentProjects};
+use egui_kittest::kittest::Queryable as _;
+use std::path::PathBuf;
+
+/// An empty directory of its own for each test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("eframe_template_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn names(recent: &RecentProjects) -> Vec<String> {
+    recent.iter().map(|entry| entry.project.name()).collect()
+}
+
+#[test]
+fn recent_projects_keep_pinned_ones_first() {
+    let mut recent = RecentProjects::default();
+    for name in ["a", "b", "c"] {
+        recent.add(Project::new(name));
+    }
+    assert_eq!(names(&recent), ["c", "b", "a"]);
+
+    recent.set_pinned(&Project::new("a"), true);
+    recent.add(Project::new("b"));
+    assert_eq!(names(&recent), ["a", "b", "c"]);
+
+    // Pinned ones are never forgotten.
+    for nr in 0..20 {
+        recent.add(Project::new(format!("new {nr}")));
+    }
+    assert_eq!(recent.iter().count(), 11);
+    assert_eq!(names(&recent)[..2], ["a", "new 19"]);
+}
+
+#[test]
+fn missing_projects_are_removed() {
+    let dir = temp_dir("missing");
+    let mut recent = RecentProjects::default();
+    recent.add(Project::new(&dir));
+    recent.add(Project::new(dir.join("gone")));
+    assert_eq!(recent.remove_missing(), 1);
+    assert_eq!(names(&recent), [dir.file_name().unwrap().to_str().unwrap()]);
+}
+
+#[test]
+fn layout_is_saved_next_to_the_project() {
+    let dir = temp_dir("layout");
+    let project = Project::new(&dir);
+    let mut harness = harness(&MemoryStorage::default());
+    harness.state_mut().open_project(project.clone());
+    harness.get_by_label("Add tab").click();
+    harness.run();
+    let titles = harness.state().pane_titles();
+    harness.state_mut().close_project();
+    assert!(harness.state().project().is_none());
+    assert!(dir.join(".eframe_template/layout.ron").exists());
+
+    let mut other = common::harness(&MemoryStorage::default());
+    assert_ne!(other.state().pane_titles(), titles);
+    other.state_mut().open_project(project);
+    assert_eq!(other.state().pane_titles(), titles);
+}
+
+#[test]
+fn recent_projects_are_persisted() {
+    let dir = temp_dir("recent");
+    let mut harness = harness(&MemoryStorage::default());
+    harness.state_mut().open_project(Project::new(&dir));
+    let storage = common::save(&mut harness);
+
+    let restored = common::harness(&storage);
+    assert_eq!(restored.state().project(), Some(&Project::new(&dir)));
+    assert_eq!(
+        names(restored.state().recent_projects()),
+        [dir.file_name().unwrap().to_str().unwrap()]
+    );
+}
@@ -0,0 +1,30 @@
+//! Parsing and formatting of deep links, see [`Route`].
+
+use eframe_template::Route;
+
+#[test]
+fn parse_hash() {
+    let route = Route::parse("#tab=TabB&nr=2");
+    assert_eq!(route.workspace, None);
+    assert_eq!(route.tab.as_deref(), Some("TabB"));
+    assert_eq!(route.nr, Some(2));
+}
+
+#[test]
+fn parse_ignores_unknown_and_malformed() {
+    let route = Route::parse("?foo=bar&nr=two&tab&workspace=a%20b");
+    assert_eq!(route.workspace.as_deref(), Some("a b"));
+    assert_eq!(route.tab, None);
+    assert_eq!(route.nr, None);
+    assert_eq!(Route::parse(""), Route::default());
+}
+
+#[test]
+fn hash_round_trip() {
+    let route = Route {
+        workspace: Some("review & merge/ü".to_owned()),
+        tab: Some("TabA".to_owned()),
+        nr: Some(0),
+    };
+    assert_eq!(Route::parse(&route.to_hash()), route);
+}
@@ -0,0 +1,57 @@
+//! Side panel sections registered by the code embedding the app.
+
+mod common;
+
+use common::{harness, MemoryStorage};
+use eframe_template::context::Context;
+use eframe_template::sidepanel::{SectionEnv, SidePanelSection};
+use egui_kittest::kittest::Queryable as _;
+
+struct Bookmarks;
+
+impl SidePanelSection for Bookmarks {
+    fn id(&self) -> &'static str {
+        "bookmarks"
+    }
+
+    fn title(&self, _cx: &Context) -> egui::WidgetText {
+        "Bookmarks".into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _env: &mut SectionEnv<'_>) {
+        ui.label("No bookmarks yet");
+    }
+}
+
+#[test]
+fn section_registered_from_outside_is_shown() {
+    let mut harness = harness(&MemoryStorage::default());
+    harness
+        .state_mut()
+        .register_side_panel_section(Box::new(Bookmarks));
+    harness.run();
+
+    assert_eq!(
+        harness.state().navigator().section_ids(),
+        ["outline", "layout", "bookmarks"]
+    );
+    // The header and its collapse toggle.
+    assert!(harness.query_all_by_label("Bookmarks").next().is_some());
+    harness.get_by_label("No bookmarks yet");
+}
+
+#[test]
+fn registered_section_survives_resetting_the_state() {
+    let mut harness = harness(&MemoryStorage::default());
+    harness
+        .state_mut()
+        .register_side_panel_section(Box::new(Bookmarks));
+    harness.run();
+
+    harness.get_by_label("Help").click();
+    harness.run();
+    harness.get_by_label("Reset mem").click();
+    harness.run();
+
+    harness.get_by_label("No bookmarks yet");
+}
@@ -0,0 +1,85 @@
+//! Image snapshot tests of the app chrome.
+//!
+//! Rendered with wgpu, on a headless machine install a software Vulkan driver
+//! (`mesa-vulkan-drivers`, lavapipe) so that rendering happens on the CPU.
+//! Run with `UPDATE_SNAPSHOTS=1 cargo test` to accept changes, the images live in `tests/snapshots`.
+//! A missing or differing image fails the test.
+//!
+//! No reference images are committed yet, so the tests are ignored until they are.
+//! Create them with `UPDATE_SNAPSHOTS=1 cargo test --test snapshots -- --ignored`,
+//! then drop the `#[ignore]`s.
+
+mod common;
+
+use common::{harness_with_context, MemoryStorage};
+use eframe_template::context::Context;
+use eframe_template::TemplateApp;
+use egui_kittest::kittest::Queryable as _;
+use egui_kittest::{Harness, SnapshotOptions};
+
+/// (menu, window title)
+const WINDOWS: [(&str, &str); 6] = [
+    ("File", "Settings"),
+    ("Window", "Log viewer"),
+    ("Window", "Debug"),
+    ("Window", "Notifications"),
+    ("Window", "Tasks"),
+    ("Help", "About"),
+];
+
+const THEMES: [(egui::Theme, &str); 2] =
+    [(egui::Theme::Light, "light"), (egui::Theme::Dark, "dark")];
+
+fn window_snapshot_name(title: &str) -> String {
+    format!("window_{}", title.to_lowercase().replace(' ', "_"))
+}
+
+/// Allow for small differences between GPU drivers and font rasterization.
+fn options() -> SnapshotOptions {
+    SnapshotOptions::new().threshold(0.6)
+}
+
+/// The app with a fixed commit, build date and renderer, they differ between builds and machines.
+fn harness() -> Harness<'static, TemplateApp> {
+    let cx = Context::new();
+    {
+        let mut diagnostics = cx.diagnostics();
+        diagnostics.git_hash = "0000000".to_owned();
+        diagnostics.build_date = "2025-01-01".to_owned();
+        diagnostics.renderer = Some("Snapshot renderer".to_owned());
+    }
+    let harness = harness_with_context(&MemoryStorage::default(), cx.clone());
+    // The harness doesn't measure CPU time, frame times would differ from run to run.
+    assert!(cx.diagnostics().frame_times.is_empty());
+    harness
+}
+
+fn snapshot_themes(harness: &mut Harness<'_, TemplateApp>, name: &str) {
+    for (theme, theme_name) in THEMES {
+        harness.ctx.set_theme(theme);
+        harness.run();
+        harness.snapshot_options(&format!("{name}_{theme_name}"), &options());
+    }
+}
+
+#[test]
+#[ignore = "no reference images yet"]
+fn default_layout() {
+    let mut harness = harness();
+    snapshot_themes(&mut harness, "default_layout");
+}
+
+#[test]
+#[ignore = "no reference images yet"]
+fn windows() {
+    for (menu, title) in WINDOWS {
+        let mut harness = harness();
+        harness.get_by_label(menu).click();
+        harness.run();
+        harness.get_by_label(title).click();
+        harness.run();
+        assert!(harness.state().is_window_open(title));
+
+        snapshot_themes(&mut harness, &window_snapshot_name(title));
+    }
+}
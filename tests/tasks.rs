@@ -0,0 +1,54 @@
+//! Background tasks spawned through the [`Context`].
+
+use eframe_template::context::Context;
+use eframe_template::tasks::{TaskId, TaskOutcome};
+use std::time::{Duration, Instant};
+
+fn wait_for_outcome(cx: &Context, id: TaskId) -> TaskOutcome {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if let Some(outcome) = cx.tasks().get(id).and_then(|task| task.outcome()) {
+            return outcome;
+        }
+        assert!(Instant::now() < deadline, "the task didn't finish");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn result_is_kept() {
+    let cx = Context::new();
+    let id = cx.spawn_task("Add", |task| {
+        task.set_progress(Some(0.5));
+        Ok((1 + 1).to_string())
+    });
+    assert_eq!(wait_for_outcome(&cx, id), TaskOutcome::Done("2".to_owned()));
+    assert_eq!(cx.tasks().get(id).unwrap().progress(), Some(0.5));
+}
+
+#[test]
+fn cancelled_task_stops() {
+    let cx = Context::new();
+    let id = cx.spawn_task("Wait for cancel", |task| {
+        while !task.is_cancelled() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        Err("stopped".to_owned())
+    });
+    assert_eq!(cx.tasks().running(), 1);
+
+    cx.tasks().get(id).unwrap().cancel();
+
+    assert_eq!(wait_for_outcome(&cx, id), TaskOutcome::Cancelled);
+    assert_eq!(cx.tasks().running(), 0);
+}
+
+#[test]
+fn panicking_task_fails() {
+    let cx = Context::new();
+    let id = cx.spawn_task("Panic", |_| panic!("boom"));
+    match wait_for_outcome(&cx, id) {
+        TaskOutcome::Failed(error) => assert!(error.contains("boom"), "{error}"),
+        outcome => panic!("unexpected outcome {outcome:?}"),
+    }
+}